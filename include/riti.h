@@ -16,6 +16,11 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/*
+ Version of the settings file format written by [`Config::save()`].
+ */
+#define CONFIG_VERSION 1
+
 /*
  Shift modifier key.
 
@@ -252,11 +257,189 @@
 
 #define VC_KP_0 82
 
+/*
+ Source of a suggestion.
+ */
+typedef enum CandidateKind {
+    /*
+     The text as the user typed it.
+     */
+    CandidateKind_Typed,
+    /*
+     An Auto Correct entry.
+     */
+    CandidateKind_AutoCorrect,
+    /*
+     A dictionary word.
+     */
+    CandidateKind_Dictionary,
+    /*
+     A dictionary word with a suffix added.
+     */
+    CandidateKind_Suffixed,
+    /*
+     An Emoji.
+     */
+    CandidateKind_Emoji,
+    /*
+     The typed English word.
+     */
+    CandidateKind_English,
+    /*
+     Phonetic transliteration of the typed text.
+     */
+    CandidateKind_Phonetic,
+    /*
+     The typed number spelled in words.
+     */
+    CandidateKind_Number,
+    /*
+     The current date or time.
+     */
+    CandidateKind_DateTime,
+    /*
+     ISO 15919 romanisation of a suggestion.
+     */
+    CandidateKind_Romanized,
+} CandidateKind;
+
+/*
+ Encoding of the suggestions.
+ */
+typedef enum Encoding {
+    /*
+     The suggestions are kept in the Unicode.
+     */
+    Encoding_Unicode,
+    /*
+     Bijoy 2000 encoding.
+     */
+    Encoding_Bijoy,
+    /*
+     ISCII encoding (IS 13194:1991).
+     */
+    Encoding_Iscii,
+} Encoding;
+
+/*
+ Input mode of the context.
+ */
+typedef enum InputMode {
+    /*
+     Bengali, with the method of the layout.
+     */
+    InputMode_Bengali,
+    /*
+     English. The typed text is passed through, with the completions from
+     the English word list of the config if there is one.
+     */
+    InputMode_English,
+} InputMode;
+
+/*
+ Purpose of the text field which is receiving the input.
+ */
+typedef enum InputPurpose {
+    /*
+     A regular text field.
+     */
+    InputPurpose_Normal,
+    /*
+     A URL field. The typed text is passed through without any conversion.
+     */
+    InputPurpose_Url,
+    /*
+     An Email address field. The typed text is passed through without any conversion.
+     */
+    InputPurpose_Email,
+    /*
+     A numeric field. Digits are produced in Bengali, the rest is passed through.
+     */
+    InputPurpose_Number,
+    /*
+     A numeric field. Digits are produced in ASCII, the rest is passed through.
+     */
+    InputPurpose_AsciiNumber,
+    /*
+     A password field. The typed text is passed through without any conversion
+     and nothing is learned.
+     */
+    InputPurpose_Password,
+    /*
+     A search box. Emoji and English candidates are not suggested.
+     */
+    InputPurpose_Search,
+} InputPurpose;
+
+/*
+ Language of the input.
+ */
+typedef enum Language {
+    /*
+     Bengali (Bangla).
+     */
+    Language_Bengali,
+    /*
+     Assamese (Asamiya), written with `ৰ` and `ৱ`.
+     */
+    Language_Assamese,
+} Language;
+
+/*
+ Target form of the normalisation.
+ */
+typedef enum Normalization {
+    /*
+     The text is kept as it is.
+     */
+    Normalization_None,
+    /*
+     Unicode Normalization Form C. The nukta letters are decomposed, like `য়`
+     as `য` followed by the nukta.
+     */
+    Normalization_Nfc,
+    /*
+     The NFC, but the nukta letters are precomposed and `ৎ` is used instead
+     of `ত` followed by the hasanta and a ZWJ.
+     */
+    Normalization_Bangla,
+} Normalization;
+
+/*
+ Group of a suggestion in the ranking, the variant of its [`Rank`].
+ */
+typedef enum RankTier {
+    /*
+     Shown before everything else.
+     */
+    RankTier_First,
+    /*
+     An Emoji, shown before the other suggestions.
+     */
+    RankTier_Emoji,
+    /*
+     The usual suggestions.
+     */
+    RankTier_Other,
+    /*
+     Shown after everything else.
+     */
+    RankTier_Last,
+} RankTier;
+
 /*
  Config struct for configuring RitiContext.
+
+ It can be stored in a versioned settings file, see [`Config::load()`]
+ and [`Config::save()`].
  */
 typedef struct Config Config;
 
+/*
+ Result of a conversion.
+ */
+typedef struct Conversion Conversion;
+
 /*
  Context handle used for libRiti IM APIs
  */
@@ -266,6 +449,10 @@ typedef struct RitiContext RitiContext;
  Suggestions which are intended to be shown by the IM's candidate window.
  Suggestion is of two variants, the 'Full' one includes a list of suggestion and
  the 'Single' one is just a String.
+
+ With the `serde` feature, it is serialized as a JSON object tagged with
+ `"type": "full"` or `"type": "single"`. The format is described by the
+ JSON schema in `schema/suggestion.schema.json`.
  */
 typedef struct Suggestion Suggestion;
 
@@ -333,25 +520,159 @@ void riti_context_finish_input_session(struct RitiContext *ptr);
 struct Suggestion *riti_context_backspace_event(struct RitiContext *ptr,
                                                 bool ctrl);
 
+/*
+ Show the next page of the candidates.
+
+ Returns a null pointer if the candidates aren't paginated.
+ */
+struct Suggestion *riti_context_page_down(struct RitiContext *ptr);
+
+/*
+ Show the previous page of the candidates.
+
+ Returns a null pointer if the candidates aren't paginated.
+ */
+struct Suggestion *riti_context_page_up(struct RitiContext *ptr);
+
+/*
+ Enable or disable the private input mode.
+
+ In private mode, nothing is learned from the user's input. It is
+ intended to be toggled when the focus moves to a password field or
+ a private chat.
+ */
+void riti_context_set_private_mode(struct RitiContext *ptr, bool enabled);
+
+/*
+ Checks if the private input mode is enabled.
+ */
+bool riti_context_is_private_mode(struct RitiContext *ptr);
+
+/*
+ Switch to the input `mode`, a value of `InputMode`. Unknown values are ignored.
+
+ The ongoing input session of the previous mode is finished.
+ */
+void riti_context_set_input_mode(struct RitiContext *ptr, uint32_t mode);
+
+/*
+ Get the input mode.
+ */
+enum InputMode riti_context_get_input_mode(struct RitiContext *ptr);
+
+/*
+ Set the purpose of the focused text field.
+
+ The engine adjusts its behaviour according to the `purpose`, a value of
+ `InputPurpose`. Unknown values are ignored.
+ */
+void riti_context_set_input_purpose(struct RitiContext *ptr, uint32_t purpose);
+
+/*
+ Switch to the configuration profile of the application `app_id`.
+
+ If the application has no profile, the config given to the context is used as it is.
+ */
+void riti_context_set_active_profile(struct RitiContext *ptr,
+                                     const char *app_id);
+
+/*
+ Set the configuration profile of the application `app_id`.
+
+ `profile` is the JSON object of the overridden options, like
+ `{"include_emoji": false, "encoding": "bijoy"}`. The options are named
+ as in the settings file.
+
+ Returns `false` if the `profile` isn't valid.
+ */
+bool riti_context_set_profile(struct RitiContext *ptr,
+                              const char *app_id,
+                              const char *profile);
+
+/*
+ Remove the configuration profile of the application `app_id`.
+ */
+void riti_context_remove_profile(struct RitiContext *ptr, const char *app_id);
+
+/*
+ Load the method of the layout `id` without activating it, so that
+ switching to it is instant.
+
+ `id` is `avro_phonetic` or the path of a fixed method layout file.
+ Returns `false` if it is neither.
+ */
+bool riti_context_load_method(struct RitiContext *ptr, const char *id);
+
+/*
+ Switch to the loaded method of the layout `id`.
+
+ The previously active method is kept loaded along with its state.
+ Returns `false` if the method of `id` isn't loaded.
+ */
+bool riti_context_switch_method(struct RitiContext *ptr, const char *id);
+
 void riti_suggestion_free(struct Suggestion *ptr);
 
 /*
  Get the suggestion of the `index` from suggestions.
+
+ Returns a null pointer if the `Suggestion` is a *lonely* one or the `index` is out of range.
  */
 char *riti_suggestion_get_suggestion(const struct Suggestion *ptr,
                                      uintptr_t index);
 
+/*
+ Get the source of the suggestion of the `index` from suggestions.
+
+ Returns `Typed` if the `Suggestion` is a *lonely* one or the `index` is out of range.
+ */
+enum CandidateKind riti_suggestion_get_candidate_kind(const struct Suggestion *ptr,
+                                                      uintptr_t index);
+
+/*
+ Get the ranking group of the suggestion of the `index` from suggestions.
+
+ Returns `Other` if the `Suggestion` is a *lonely* one or the `index` is out of range.
+ */
+enum RankTier riti_suggestion_get_candidate_tier(const struct Suggestion *ptr,
+                                                 uintptr_t index);
+
+/*
+ Get the score of the suggestion of the `index` from suggestions.
+
+ Returns `0` if the `Suggestion` is a *lonely* one or the `index` is out of range.
+ */
+uint8_t riti_suggestion_get_candidate_score(const struct Suggestion *ptr,
+                                            uintptr_t index);
+
+/*
+ Get the annotation of the suggestion of the `index` from suggestions.
+
+ Returns a null pointer if the suggestion has no annotation, the `Suggestion`
+ is a *lonely* one or the `index` is out of range.
+ */
+char *riti_suggestion_get_candidate_annotation(const struct Suggestion *ptr,
+                                               uintptr_t index);
+
 /*
  Get the only suggestion of the *lonely* `Suggestion`.
+
+ Returns a null pointer if the `Suggestion` is not a *lonely* one.
  */
 char *riti_suggestion_get_lonely_suggestion(const struct Suggestion *ptr);
 
+/*
+ Get the auxiliary text.
+
+ Returns a null pointer if the `Suggestion` is a *lonely* one.
+ */
 char *riti_suggestion_get_auxiliary_text(const struct Suggestion *ptr);
 
 /*
  Get the pre-edit text from the list of the `index'.
 
  This returns the lone suggestion if the suggestion is a lonely one.
+ Returns a null pointer if the `index` is out of range.
 
  The main purpose of the function is to convert the returning suggestion into
  the ANSI encoding if it was specified when the instance of this `Suggestion`
@@ -360,6 +681,15 @@ char *riti_suggestion_get_auxiliary_text(const struct Suggestion *ptr);
 char *riti_suggestion_get_pre_edit_text(const struct Suggestion *ptr,
                                         uintptr_t index);
 
+#if defined(RITI_SERDE)
+/*
+ Serializes the `Suggestion` into JSON.
+
+ The format is described by the JSON schema in `schema/suggestion.schema.json`.
+ */
+char *riti_suggestion_to_json(const struct Suggestion *ptr);
+#endif
+
 /*
  Free the allocated string.
  */
@@ -367,11 +697,25 @@ void riti_string_free(char *ptr);
 
 /*
  Returns index of the suggestion, which was previously selected.
+
+ Returns `0` if the `Suggestion` is a *lonely* one.
  */
 uintptr_t riti_suggestion_previously_selected_index(const struct Suggestion *ptr);
 
 uintptr_t riti_suggestion_get_length(const struct Suggestion *ptr);
 
+/*
+ Returns index of the current page of the suggestions.
+
+ Returns `0` if the `Suggestion` is a *lonely* one.
+ */
+uintptr_t riti_suggestion_current_page(const struct Suggestion *ptr);
+
+/*
+ Get the number of suggestions in all of the pages.
+ */
+uintptr_t riti_suggestion_get_total_length(const struct Suggestion *ptr);
+
 /*
  Returns `true` when the `Suggestion` struct is a **lonely** one, otherwise returns `false`.
 
@@ -381,6 +725,54 @@ bool riti_suggestion_is_lonely(const struct Suggestion *ptr);
 
 bool riti_suggestion_is_empty(const struct Suggestion *ptr);
 
+/*
+ Converts the Unicode `text` into the `encoding`.
+
+ The `encoding` is a value of `Encoding`. Null is returned for unknown values.
+ */
+struct Conversion *riti_conversion_encode(const char *text, uint32_t encoding);
+
+/*
+ Converts the `text` of the `encoding` into the Unicode.
+
+ The `encoding` is a value of `Encoding`. Null is returned for unknown values.
+ */
+struct Conversion *riti_conversion_decode(const char *text, uint32_t encoding);
+
+void riti_conversion_free(struct Conversion *ptr);
+
+/*
+ Get the converted text.
+ */
+char *riti_conversion_get_text(const struct Conversion *ptr);
+
+/*
+ Get the bytes of the text converted into a legacy encoding, as a
+ NUL-terminated string.
+
+ Returns a null pointer if the text has a character which isn't in the
+ Windows-1252 code page or an unmapped character kept as it is.
+ */
+char *riti_conversion_get_bytes(const struct Conversion *ptr);
+
+/*
+ Get the number of the pieces of the text which couldn't be mapped.
+ */
+uintptr_t riti_conversion_get_unmapped_length(const struct Conversion *ptr);
+
+/*
+ Get the piece of the `index` among the ones which couldn't be mapped.
+
+ Returns a null pointer if the `index` is out of range.
+ */
+char *riti_conversion_get_unmapped(const struct Conversion *ptr,
+                                   uintptr_t index);
+
+/*
+ Romanises the Bengali `text` by ISO 15919.
+ */
+char *riti_romanize(const char *text);
+
 /*
  Creates a new instance of Config which is used to initialize
  and to control the configuration of RitiContext.
@@ -391,6 +783,20 @@ bool riti_suggestion_is_empty(const struct Suggestion *ptr);
  */
 struct Config *riti_config_new(void);
 
+/*
+ Loads a Config from the settings file at `path`.
+
+ Returns a null pointer if the file couldn't be loaded.
+ */
+struct Config *riti_config_load(const char *path);
+
+/*
+ Saves the Config into the settings file at `path`.
+
+ Returns `true` if the file was saved successfully.
+ */
+bool riti_config_save(const struct Config *ptr, const char *path);
+
 /*
  Free the allocated Config struct.
  */
@@ -421,8 +827,15 @@ bool riti_config_set_user_dir(struct Config *ptr, const char *path);
 void riti_config_set_suggestion_include_english(struct Config *ptr,
                                                 bool option);
 
+void riti_config_set_suggestion_include_emoji(struct Config *ptr, bool option);
+
 void riti_config_set_phonetic_suggestion(struct Config *ptr, bool option);
 
+/*
+ Set the case-insensitive phonetic input.
+ */
+void riti_config_set_phonetic_case_insensitive(struct Config *ptr, bool option);
+
 void riti_config_set_fixed_suggestion(struct Config *ptr, bool option);
 
 void riti_config_set_fixed_auto_vowel(struct Config *ptr, bool option);
@@ -439,8 +852,89 @@ void riti_config_set_fixed_old_kar_order(struct Config *ptr, bool option);
 
 void riti_config_set_ansi_encoding(struct Config *ptr, bool option);
 
+/*
+ Set the `encoding` of the output, a value of `Encoding`. Unknown values are ignored.
+ */
+void riti_config_set_encoding(struct Config *ptr,
+                              uint32_t encoding);
+
+/*
+ Set the `language` of the input, a value of `Language`. Unknown values are ignored.
+ */
+void riti_config_set_language(struct Config *ptr,
+                              uint32_t language);
+
+/*
+ Sets the number of candidates shown in a page, `0` disables pagination.
+ */
+void riti_config_set_page_size(struct Config *ptr, uintptr_t size);
+
+/*
+ Sets the key code which toggles the English mode, `0` disables the key.
+ */
+void riti_config_set_english_toggle_key(struct Config *ptr, uint16_t key);
+
+/*
+ Sets the path of the word list used for the completions of the English mode.
+
+ Returns `true` if the path exists.
+ */
+bool riti_config_set_english_word_list(struct Config *ptr, const char *path);
+
 void riti_config_set_smart_quote(struct Config *ptr, bool option);
 
+/*
+ Set the Bengali style quotation marks of the smart quote.
+ */
+void riti_config_set_smart_quote_bengali(struct Config *ptr, bool option);
+
+/*
+ Set the suggestion of the typed numbers with the digit grouping.
+ */
+void riti_config_set_number_formatting(struct Config *ptr, bool option);
+
+/*
+ Set the suggestion of the typed numbers spelled in words.
+ */
+void riti_config_set_number_words(struct Config *ptr, bool option);
+
+/*
+ Set the normalisation `form` of the suggestions, a value of `Normalization`.
+ Unknown values are ignored.
+ */
+void riti_config_set_normalization(struct Config *ptr, uint32_t form);
+
+/*
+ Set the suggestion of the ISO 15919 romanisation of the best suggestion.
+ */
+void riti_config_set_romanization(struct Config *ptr, bool option);
+
+/*
+ Set the smart punctuation of `...` as `…`.
+ */
+void riti_config_set_punctuation_ellipsis(struct Config *ptr, bool option);
+
+/*
+ Set the smart punctuation of `--` as `—`.
+ */
+void riti_config_set_punctuation_dash(struct Config *ptr, bool option);
+
+/*
+ Set the smart punctuation of a sentence-final `.` as `।`.
+ */
+void riti_config_set_punctuation_dari(struct Config *ptr, bool option);
+
+/*
+ Set the smart punctuation of `।।` as `॥`.
+ */
+void riti_config_set_punctuation_double_dari(struct Config *ptr, bool option);
+
+/*
+ Set the smart spacing between a punctuation mark and the following Bengali word.
+ */
+void riti_config_set_punctuation_spacing(struct Config *ptr,
+                                         bool option);
+
 #ifdef __cplusplus
 }  // extern "C"
 #endif  // __cplusplus
@@ -0,0 +1,310 @@
+//! A JSON-RPC server for the editors and the applications which can't link
+//! the library.
+//!
+//! Requests and responses are [JSON-RPC 2.0](https://www.jsonrpc.org/specification)
+//! messages, one message per line. The server reads the requests from the
+//! standard input and writes the responses to the standard output, or serves
+//! the connections of a Unix socket when the `--socket` option is given.
+//!
+//! Methods:
+//! * `key {key, modifier?, selection?}`: A key press, returns the suggestion.
+//! * `text {text}`: Types the `text` key by key, returns the last suggestion.
+//! * `commit {index}`: Commits the candidate of the `index`.
+//! * `backspace {ctrl?}`: A BackSpace key press, returns the suggestion.
+//! * `finish`: Finishes the ongoing input session.
+//! * `page_up`, `page_down`: Turns the page of the candidates, returns the suggestion.
+//! * `config {...}`: Updates the options of the settings file format.
+//! * `transliterate {text}`: Returns the Avro Phonetic transliteration of the `text`.
+//!
+//! Suggestions are serialized as described by `schema/suggestion.schema.json`.
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::process::exit;
+
+use riti::config::Config;
+use riti::context::RitiContext;
+use riti::keycodes::char_to_keycode;
+use riti::phonetic::transliterate;
+use riti::suggestion::Suggestion;
+use serde_json::{json, Map, Value};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+const USAGE: &str = "Usage: riti-server [--config <settings file>] [--socket <path>]";
+
+struct Error {
+    code: i64,
+    message: String,
+}
+
+impl Error {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Error {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+}
+
+struct Server {
+    context: RitiContext,
+    config: Config,
+}
+
+impl Server {
+    fn new(config: Config) -> Self {
+        Server {
+            context: RitiContext::new_with_config(&config),
+            config,
+        }
+    }
+
+    /// Serves the requests of the `input` until it is closed.
+    fn serve(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(response) = self.handle(&line) {
+                writeln!(output, "{response}")?;
+                output.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles the request `line` and returns the response.
+    ///
+    /// Notifications, valid requests without an `id`, have no response.
+    /// Invalid requests are answered with a `null` id if they have none.
+    fn handle(&mut self, line: &str) -> Option<Value> {
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => {
+                return Some(error_response(
+                    Value::Null,
+                    Error::new(PARSE_ERROR, e.to_string()),
+                ))
+            }
+        };
+
+        let id = request.get("id").cloned();
+        // The method of a valid request.
+        let method = request
+            .get("method")
+            .and_then(Value::as_str)
+            .filter(|_| request.get("jsonrpc") == Some(&json!("2.0")));
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = match method {
+            Some(method) => catch_unwind(AssertUnwindSafe(|| self.call(method, &params)))
+                .unwrap_or_else(|_| Err(Error::new(INTERNAL_ERROR, "internal error"))),
+            None => Err(Error::new(INVALID_REQUEST, "invalid request")),
+        };
+
+        let id = match id {
+            Some(id) => id,
+            None if method.is_some() => return None,
+            None => Value::Null,
+        };
+        Some(match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err(error) => error_response(id, error),
+        })
+    }
+
+    fn call(&mut self, method: &str, params: &Value) -> Result<Value, Error> {
+        let context = &self.context;
+
+        match method {
+            "key" => {
+                let key =
+                    param_u64(params, "key")?.ok_or_else(|| Error::params("missing `key`"))?;
+                let modifier = param_u64(params, "modifier")?.unwrap_or(0);
+                let selection = param_u64(params, "selection")?.unwrap_or(0);
+                let suggestion = context.get_suggestion_for_key(
+                    int(key, "key")?,
+                    int(modifier, "modifier")?,
+                    int(selection, "selection")?,
+                );
+                Ok(to_value(Some(suggestion)))
+            }
+            "text" => {
+                let keys = param_str(params, "text")?
+                    .chars()
+                    .map(|c| {
+                        char_to_keycode(c).ok_or_else(|| Error::params(format!("can't type `{c}`")))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let suggestion = keys
+                    .into_iter()
+                    .map(|key| context.get_suggestion_for_key(key, 0, 0))
+                    .last();
+                Ok(to_value(suggestion))
+            }
+            "commit" => {
+                let index =
+                    param_u64(params, "index")?.ok_or_else(|| Error::params("missing `index`"))?;
+                context.candidate_committed(int(index, "index")?);
+                Ok(Value::Null)
+            }
+            "backspace" => {
+                let ctrl = match params.get("ctrl") {
+                    None => false,
+                    Some(ctrl) => ctrl
+                        .as_bool()
+                        .ok_or_else(|| Error::params("`ctrl` must be a boolean"))?,
+                };
+                Ok(to_value(Some(context.backspace_event(ctrl))))
+            }
+            "finish" => {
+                context.finish_input_session();
+                Ok(Value::Null)
+            }
+            "page_up" => Ok(to_value(context.page_up())),
+            "page_down" => Ok(to_value(context.page_down())),
+            "config" => {
+                let Value::Object(options) = params else {
+                    return Err(Error::params("the options must be an object"));
+                };
+                // Only the given options are changed.
+                let mut settings: Map<String, Value> =
+                    serde_json::from_str(&self.config.to_json()).unwrap();
+                settings.extend(options.clone());
+                let config = Config::from_json(&Value::Object(settings).to_string())
+                    .map_err(|e| Error::params(e.to_string()))?;
+                self.context.update_engine(&config);
+                self.config = config;
+                Ok(Value::Null)
+            }
+            "transliterate" => Ok(json!(transliterate(param_str(params, "text")?))),
+            _ => Err(Error::new(
+                METHOD_NOT_FOUND,
+                format!("method `{method}` not found"),
+            )),
+        }
+    }
+}
+
+/// Serializes the `suggestion`, `null` if there is none.
+fn to_value(suggestion: Option<Suggestion>) -> Value {
+    serde_json::to_value(suggestion).unwrap()
+}
+
+fn error_response(id: Value, error: Error) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": error.code, "message": error.message},
+    })
+}
+
+fn param_u64(params: &Value, name: &str) -> Result<Option<u64>, Error> {
+    match params.get(name) {
+        None => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| Error::params(format!("`{name}` must be a non-negative integer"))),
+    }
+}
+
+fn param_str<'a>(params: &'a Value, name: &str) -> Result<&'a str, Error> {
+    params
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::params(format!("`{name}` must be a string")))
+}
+
+fn int<T: TryFrom<u64>>(value: u64, name: &str) -> Result<T, Error> {
+    T::try_from(value).map_err(|_| Error::params(format!("`{name}` is out of range")))
+}
+
+fn main() {
+    let mut config_path = None;
+    let mut socket = None;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = args.next(),
+            "--socket" => socket = args.next(),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => {
+                eprintln!("{USAGE}");
+                exit(2);
+            }
+        }
+    }
+
+    let config = match config_path {
+        Some(path) => Config::load(&path).unwrap_or_else(|e| {
+            eprintln!("riti-server: couldn't load {path}: {e}");
+            exit(1);
+        }),
+        None => {
+            let mut config = Config::default();
+            config.set_layout_file_path("avro_phonetic");
+            config.set_phonetic_suggestion(true);
+            config
+        }
+    };
+
+    let mut server = Server::new(config);
+
+    let result = match socket {
+        Some(path) => serve_socket(&mut server, &path),
+        None => server.serve(io::stdin().lock(), io::stdout().lock()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("riti-server: {e}");
+        exit(1);
+    }
+}
+
+#[cfg(unix)]
+fn serve_socket(server: &mut Server, path: &str) -> io::Result<()> {
+    use std::fs;
+    use std::io::BufReader;
+    use std::os::unix::net::UnixListener;
+
+    let listener = UnixListener::bind(path)?;
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let input = BufReader::new(stream.try_clone()?);
+        // A broken connection shouldn't stop the server.
+        if let Err(e) = server.serve(input, stream) {
+            eprintln!("riti-server: {e}");
+        }
+        server.context.finish_input_session();
+    }
+
+    fs::remove_file(path)
+}
+
+#[cfg(not(unix))]
+fn serve_socket(_: &mut Server, _: &str) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Unix sockets are not supported on this platform",
+    ))
+}
@@ -0,0 +1,241 @@
+//! A command-line tool to use and inspect the riti engine.
+//!
+//! Subcommands:
+//! * `transliterate [FILE]...`: Transliterates the files, or the standard input,
+//!   with the Avro Phonetic.
+//! * `suggest <WORD>`: Prints the ranked candidates of the `WORD`, with their
+//!   rank, source and score.
+//! * `layout show <FILE>`: Prints the characters of a fixed method layout.
+//! * `autocorrect lookup <WORD>`: Prints the Auto Correct entries of the `WORD`.
+//!
+//! `suggest` and `autocorrect` accept `--config <settings file>`, `suggest` also
+//! accepts `--layout <layout file>` to use a fixed method layout.
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::exit;
+
+use riti::config::Config;
+use riti::context::RitiContext;
+use riti::fixed::layout::{Layout, LayoutModifiers};
+use riti::keycodes::char_to_keycode;
+use riti::phonetic::{search_autocorrect, search_user_autocorrect, transliterate};
+
+const USAGE: &str = "\
+Usage: riti transliterate [FILE]...
+       riti suggest [--config <settings file>] [--layout <layout file>] <WORD>
+       riti layout show <FILE>
+       riti autocorrect lookup [--config <settings file>] <WORD>";
+
+/// Options and positional arguments of a subcommand.
+struct Args {
+    config: Option<String>,
+    layout: Option<String>,
+    free: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            config: None,
+            layout: None,
+            free: Vec::new(),
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--config" => parsed.config = Some(args.next().ok_or("missing the settings file")?),
+                "--layout" => parsed.layout = Some(args.next().ok_or("missing the layout file")?),
+                "--" => parsed.free.extend(args.by_ref()),
+                _ if arg.starts_with("--") => return Err(format!("unknown option `{arg}`")),
+                _ => parsed.free.push(arg),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns the only positional argument.
+    fn single(&self) -> Result<&str, String> {
+        match self.free.as_slice() {
+            [arg] => Ok(arg),
+            _ => Err(USAGE.to_owned()),
+        }
+    }
+
+    fn config(&self) -> Result<Config, String> {
+        let mut config = match &self.config {
+            Some(path) => Config::load(path).map_err(|e| format!("couldn't load {path}: {e}"))?,
+            None => {
+                let mut config = Config::default();
+                config.set_layout_file_path("avro_phonetic");
+                config
+            }
+        };
+
+        if let Some(layout) = &self.layout {
+            if !config.set_layout_file_path(layout) {
+                return Err(format!("{layout} doesn't exist"));
+            }
+            config.set_fixed_suggestion(true);
+        }
+        config.set_phonetic_suggestion(true);
+
+        Ok(config)
+    }
+}
+
+fn transliterate_files(args: &Args, output: &mut impl Write) -> Result<(), String> {
+    let mut inputs: Vec<Box<dyn BufRead>> = Vec::new();
+
+    if args.free.is_empty() {
+        inputs.push(Box::new(io::stdin().lock()));
+    }
+
+    for path in &args.free {
+        let file = File::open(path).map_err(|e| format!("couldn't open {path}: {e}"))?;
+        inputs.push(Box::new(BufReader::new(file)));
+    }
+
+    for input in inputs {
+        for line in input.lines() {
+            let line = line.map_err(|e| e.to_string())?;
+            writeln!(output, "{}", transliterate(&line)).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn suggest(args: &Args, output: &mut impl Write) -> Result<(), String> {
+    let word = args.single()?;
+    let context = RitiContext::new_with_config(&args.config()?);
+
+    let mut suggestion = None;
+    for c in word.chars() {
+        let key = char_to_keycode(c).ok_or_else(|| format!("can't type `{c}`"))?;
+        suggestion = Some(context.get_suggestion_for_key(key, 0, 0));
+    }
+    context.finish_input_session();
+
+    let Some(suggestion) = suggestion else {
+        return Ok(());
+    };
+
+    let result = match suggestion.get_candidates() {
+        Some(candidates) => candidates
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, candidate)| {
+                write!(
+                    output,
+                    "{}. {} ({:?}, {:?}, score {})",
+                    i + 1,
+                    candidate.text,
+                    candidate.tier,
+                    candidate.kind,
+                    candidate.score
+                )?;
+                match &candidate.annotation {
+                    Some(annotation) => writeln!(output, " [{annotation}]"),
+                    None => writeln!(output),
+                }
+            }),
+        None => writeln!(
+            output,
+            "{}",
+            suggestion.get_lonely_suggestion().unwrap_or_default()
+        ),
+    };
+
+    result.map_err(|e| e.to_string())
+}
+
+fn show_layout(args: &Args, output: &mut impl Write) -> Result<(), String> {
+    let path = args.single()?;
+    let mut config = Config::default();
+
+    if !config.set_layout_file_path(path) {
+        return Err(format!("{path} doesn't exist"));
+    }
+
+    let layout = config
+        .get_layout()
+        .and_then(Layout::parse)
+        .ok_or_else(|| format!("{path} isn't a valid layout file"))?;
+
+    // Only the keys of the printable ASCII characters are shown.
+    for c in '!'..='~' {
+        let Some(key) = char_to_keycode(c) else {
+            continue;
+        };
+        let normal = layout.get_char_for_key(key, LayoutModifiers::Normal, false);
+        let altgr = layout.get_char_for_key(key, LayoutModifiers::AltGr, false);
+
+        if normal.is_none() && altgr.is_none() {
+            continue;
+        }
+
+        writeln!(
+            output,
+            "{c}\t{}\t{}",
+            normal.unwrap_or_default(),
+            altgr.unwrap_or_default()
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+fn lookup_autocorrect(args: &Args, output: &mut impl Write) -> Result<(), String> {
+    let word = args.single()?;
+    let config = args.config()?;
+    let entries = [
+        ("user", search_user_autocorrect(word, &config)),
+        ("bundled", search_autocorrect(word)),
+    ];
+
+    if entries.iter().all(|(_, entry)| entry.is_none()) {
+        return Err(format!("no Auto Correct entry for `{word}`"));
+    }
+
+    for (source, entry) in entries {
+        if let Some(entry) = entry {
+            writeln!(output, "{source}\t{entry}\t{}", transliterate(&entry))
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let command = args.next().ok_or(USAGE)?;
+    let mut output = io::stdout().lock();
+
+    match command.as_str() {
+        "transliterate" => transliterate_files(&Args::parse(args)?, &mut output),
+        "suggest" => suggest(&Args::parse(args)?, &mut output),
+        "layout" if args.next().as_deref() == Some("show") => {
+            show_layout(&Args::parse(args)?, &mut output)
+        }
+        "autocorrect" if args.next().as_deref() == Some("lookup") => {
+            lookup_autocorrect(&Args::parse(args)?, &mut output)
+        }
+        "-h" | "--help" => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn main() {
+    if let Err(e) = run(env::args().skip(1)) {
+        eprintln!("riti: {e}");
+        exit(1);
+    }
+}
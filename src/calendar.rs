@@ -0,0 +1,377 @@
+//! Dates and times in Bengali, including the Bangla calendar (বঙ্গাব্দ).
+//!
+//! The Bangla dates follow the calendar revised by the Bangla Academy in 2019,
+//! which starts the year on the 14th of April.
+
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::number::{convert_digits, Digits};
+
+const GREGORIAN_MONTHS: [&str; 12] = [
+    "জানুয়ারি",
+    "ফেব্রুয়ারি",
+    "মার্চ",
+    "এপ্রিল",
+    "মে",
+    "জুন",
+    "জুলাই",
+    "আগস্ট",
+    "সেপ্টেম্বর",
+    "অক্টোবর",
+    "নভেম্বর",
+    "ডিসেম্বর",
+];
+
+const BANGLA_MONTHS: [&str; 12] = [
+    "বৈশাখ",
+    "জ্যৈষ্ঠ",
+    "আষাঢ়",
+    "শ্রাবণ",
+    "ভাদ্র",
+    "আশ্বিন",
+    "কার্তিক",
+    "অগ্রহায়ণ",
+    "পৌষ",
+    "মাঘ",
+    "ফাল্গুন",
+    "চৈত্র",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "রবিবার",
+    "সোমবার",
+    "মঙ্গলবার",
+    "বুধবার",
+    "বৃহস্পতিবার",
+    "শুক্রবার",
+    "শনিবার",
+];
+
+/// A local date and time of the Gregorian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: i32,
+    /// Month, from 1 to 12.
+    pub month: u8,
+    /// Day of the month, from 1.
+    pub day: u8,
+    /// Hour, from 0 to 23.
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl DateTime {
+    /// Day of the week, from 0 for Sunday to 6 for Saturday.
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 was a Thursday.
+        (days_from_civil(self.year, self.month, self.day) + 4).rem_euclid(7) as u8
+    }
+
+    /// The date in the Bangla calendar.
+    pub fn bangla_date(&self) -> BanglaDate {
+        // The year starts on the 14th of April.
+        let start = if (self.month, self.day) >= (4, 14) {
+            self.year
+        } else {
+            self.year - 1
+        };
+        let mut days =
+            days_from_civil(self.year, self.month, self.day) - days_from_civil(start, 4, 14);
+
+        // The first six months have 31 days, Falgun has 29 days or 30 in a
+        // leap year and the rest have 30 days.
+        let mut month = 0;
+        loop {
+            let length = match month {
+                0..=5 => 31,
+                10 if is_leap_year(start + 1) => 30,
+                10 => 29,
+                _ => 30,
+            };
+            if days < length {
+                break;
+            }
+            days -= length;
+            month += 1;
+        }
+
+        BanglaDate {
+            year: start - 593,
+            month: month + 1,
+            day: days as u8 + 1,
+        }
+    }
+}
+
+/// A date of the Bangla calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BanglaDate {
+    pub year: i32,
+    /// Month, from 1 for Boishakh to 12 for Choitro.
+    pub month: u8,
+    /// Day of the month, from 1.
+    pub day: u8,
+}
+
+/// Source of the current local date and time.
+///
+/// It is set with [`Config::set_clock()`](crate::config::Config::set_clock),
+/// [`SystemClock`] is used by default.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current local date and time.
+    fn now(&self) -> DateTime;
+}
+
+/// Clock of the system, in its time zone.
+///
+/// The time is in UTC on the platforms other than Unix.
+#[derive(Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[cfg(unix)]
+    fn now(&self) -> DateTime {
+        // SAFETY: `localtime_r` only writes into the given `tm`.
+        unsafe {
+            let time = libc::time(std::ptr::null_mut());
+            let mut tm = std::mem::zeroed::<libc::tm>();
+
+            if libc::localtime_r(&time, &mut tm).is_null() {
+                return utc_now();
+            }
+
+            DateTime {
+                year: tm.tm_year + 1900,
+                month: tm.tm_mon as u8 + 1,
+                day: tm.tm_mday as u8,
+                hour: tm.tm_hour as u8,
+                minute: tm.tm_min as u8,
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn now(&self) -> DateTime {
+        utc_now()
+    }
+}
+
+/// Clock which always returns the same date and time.
+#[derive(Debug)]
+pub struct FixedClock(pub DateTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime {
+        self.0
+    }
+}
+
+/// Returns the date like `১৮ অক্টোবর ২০২৬`.
+pub fn format_date(date: &DateTime) -> String {
+    convert_digits(
+        &format!(
+            "{} {} {}",
+            date.day,
+            GREGORIAN_MONTHS[date.month as usize - 1],
+            date.year
+        ),
+        Digits::Bengali,
+    )
+}
+
+/// Returns the date along with the weekday, like `রবিবার, ১৮ অক্টোবর ২০২৬`.
+pub fn format_date_with_weekday(date: &DateTime) -> String {
+    format!(
+        "{}, {}",
+        WEEKDAYS[date.weekday() as usize],
+        format_date(date)
+    )
+}
+
+/// Returns the date of the Bangla calendar, like `২ কার্তিক ১৪৩৩ বঙ্গাব্দ`.
+pub fn format_bangla_date(date: &DateTime) -> String {
+    let date = date.bangla_date();
+    convert_digits(
+        &format!(
+            "{} {} {} বঙ্গাব্দ",
+            date.day,
+            BANGLA_MONTHS[date.month as usize - 1],
+            date.year
+        ),
+        Digits::Bengali,
+    )
+}
+
+/// Returns the time in the 12-hour format along with the period of the day,
+/// like `বিকাল ৪:০৫`.
+pub fn format_time_12(time: &DateTime) -> String {
+    let period = match time.hour {
+        4..=5 => "ভোর",
+        6..=11 => "সকাল",
+        12..=14 => "দুপুর",
+        15..=17 => "বিকাল",
+        18..=19 => "সন্ধ্যা",
+        _ => "রাত",
+    };
+    let hour = match time.hour % 12 {
+        0 => 12,
+        hour => hour,
+    };
+
+    convert_digits(
+        &format!("{period} {hour}:{:02}", time.minute),
+        Digits::Bengali,
+    )
+}
+
+/// Returns the time in the 24-hour format, like `১৬:০৫`.
+pub fn format_time_24(time: &DateTime) -> String {
+    convert_digits(
+        &format!("{:02}:{:02}", time.hour, time.minute),
+        Digits::Bengali,
+    )
+}
+
+/// Returns the candidates of the `now` date.
+pub(crate) fn date_suggestions(now: &DateTime) -> Vec<String> {
+    vec![
+        format_date(now),
+        format_date_with_weekday(now),
+        format_bangla_date(now),
+        convert_digits(
+            &format!("{:02}/{:02}/{}", now.day, now.month, now.year),
+            Digits::Bengali,
+        ),
+    ]
+}
+
+/// Returns the candidates of the `now` time.
+pub(crate) fn time_suggestions(now: &DateTime) -> Vec<String> {
+    vec![format_time_12(now), format_time_24(now)]
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days since 1970-01-01 of the date.
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    // Howard Hinnant's algorithm, the year starts in March.
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// The date of the number of `days` since 1970-01-01.
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as i32, month as u8, day as u8)
+}
+
+fn utc_now() -> DateTime {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default();
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let seconds = seconds.rem_euclid(86400);
+
+    DateTime {
+        year,
+        month,
+        day,
+        hour: (seconds / 3600) as u8,
+        minute: (seconds % 3600 / 60) as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> DateTime {
+        DateTime {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+        }
+    }
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        for days in [-1, 0, 11016, 11017, 20744] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(date(2026, 10, 18).weekday(), 0);
+        assert_eq!(date(2024, 2, 29).weekday(), 4);
+    }
+
+    #[test]
+    fn test_bangla_date() {
+        let bangla = |year, month, day| {
+            let date = date(year, month, day).bangla_date();
+            (date.year, date.month, date.day)
+        };
+
+        assert_eq!(bangla(2026, 4, 14), (1433, 1, 1));
+        assert_eq!(bangla(2026, 4, 13), (1432, 12, 30));
+        assert_eq!(bangla(2026, 10, 18), (1433, 7, 2));
+        // Victory Day, Language Martyrs' Day and Independence Day.
+        assert_eq!(bangla(2025, 12, 16), (1432, 9, 1));
+        assert_eq!(bangla(2024, 2, 21), (1430, 11, 8));
+        assert_eq!(bangla(2025, 3, 26), (1431, 12, 12));
+        // Falgun has 30 days in a leap year.
+        assert_eq!(bangla(2024, 3, 14), (1430, 11, 30));
+        assert_eq!(bangla(2025, 3, 14), (1431, 11, 29));
+        assert_eq!(bangla(2025, 3, 15), (1431, 12, 1));
+    }
+
+    #[test]
+    fn test_format() {
+        let now = DateTime {
+            year: 2026,
+            month: 10,
+            day: 8,
+            hour: 16,
+            minute: 5,
+        };
+
+        assert_eq!(
+            date_suggestions(&now),
+            [
+                "৮ অক্টোবর ২০২৬",
+                "বৃহস্পতিবার, ৮ অক্টোবর ২০২৬",
+                "২৩ আশ্বিন ১৪৩৩ বঙ্গাব্দ",
+                "০৮/১০/২০২৬"
+            ]
+        );
+        assert_eq!(time_suggestions(&now), ["বিকাল ৪:০৫", "১৬:০৫"]);
+
+        let midnight = DateTime { hour: 0, ..now };
+        assert_eq!(format_time_12(&midnight), "রাত ১২:০৫");
+        let noon = DateTime { hour: 12, ..now };
+        assert_eq!(format_time_12(&noon), "দুপুর ১২:০৫");
+    }
+}
@@ -1,29 +1,91 @@
 use std::{
     env::var,
-    fs::read_to_string,
+    error::Error,
+    fmt,
+    fs::{read_to_string, write},
+    io,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
-use serde_json::Value;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::calendar::{Clock, SystemClock};
+use crate::context::InputPurpose;
+use crate::encoding::Encoding;
+use crate::language::Language;
+use crate::normalization::Normalization;
+use crate::store::{FileStore, MemoryStore, UserStore};
+
+/// Version of the settings file format written by [`Config::save()`].
+pub const CONFIG_VERSION: u64 = 1;
 
 /// Config struct for configuring RitiContext.
-#[derive(Clone, Debug)]
+///
+/// It can be stored in a versioned settings file, see [`Config::load()`]
+/// and [`Config::save()`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct Config {
     layout: String,
     database_dir: PathBuf,
     user_dir: PathBuf,
+    #[serde(skip)]
+    user_store: Arc<dyn UserStore>,
+    // Language of the input.
+    language: Language,
     include_english: bool,
+    include_emoji: bool,
     phonetic_suggestion: bool,
+    phonetic_case_insensitive: bool,
     fixed_suggestion: bool,
+    #[serde(rename = "fixed_automatic_vowel")]
     fixed_vowel: bool,
+    #[serde(rename = "fixed_automatic_chandra")]
     fixed_chandra: bool,
+    #[serde(rename = "fixed_traditional_kar")]
     fixed_kar: bool,
     fixed_old_reph: bool,
     fixed_numpad: bool,
+    #[serde(rename = "fixed_old_kar_order")]
     fixed_kar_order: bool,
-    // Output in ANSI encoding
-    ansi: bool,
+    // Encoding of the output.
+    encoding: Encoding,
     smart_quote: bool,
+    // Bengali style quotation marks of the smart quote.
+    smart_quote_bengali: bool,
+    // Suggest the typed numbers with the digit grouping.
+    number_formatting: bool,
+    // Suggest the typed numbers spelled in words.
+    number_words: bool,
+    // Normalisation of the suggestions.
+    normalization: Normalization,
+    // Suggest the ISO 15919 romanisation of the best suggestion.
+    romanization: bool,
+    // Smart punctuation rules.
+    punctuation_ellipsis: bool,
+    punctuation_dash: bool,
+    punctuation_dari: bool,
+    punctuation_double_dari: bool,
+    punctuation_spacing: bool,
+    // Number of candidates in a page, 0 disables pagination.
+    page_size: usize,
+    // Key which toggles the English mode, 0 disables the key.
+    english_toggle_key: u16,
+    // Word list for the completions of the English mode.
+    english_word_list: PathBuf,
+    // Phonetic words which are suggested with the current date or time.
+    date_triggers: Vec<String>,
+    time_triggers: Vec<String>,
+    #[serde(skip)]
+    clock: Arc<dyn Clock>,
+    // Private input mode, no user data is learned.
+    #[serde(skip)]
+    private_mode: bool,
+    // Purpose of the focused text field.
+    #[serde(skip)]
+    input_purpose: InputPurpose,
 }
 
 impl Config {
@@ -40,6 +102,15 @@ impl Config {
         }
     }
 
+    /// Sets the identifier of a method registered in the
+    /// [`MethodRegistry`](crate::context::MethodRegistry) as the layout.
+    ///
+    /// Unlike [`set_layout_file_path()`](Self::set_layout_file_path), the
+    /// identifier isn't checked.
+    pub fn set_layout_id(&mut self, id: &str) {
+        self.layout = id.into();
+    }
+
     pub fn get_layout_file_path(&self) -> &str {
         &self.layout
     }
@@ -58,16 +129,30 @@ impl Config {
 
     /// Sets the user specific writable directory path.
     ///
+    /// The user data is then stored in this directory, replacing any
+    /// store set by [`set_user_store()`](Self::set_user_store).
+    ///
     /// Returns `true` if the path exists.
     pub fn set_user_dir(&mut self, path: &str) -> bool {
         if Path::new(path).exists() {
             self.user_dir = path.into();
+            self.user_store = Arc::new(FileStore::new(path));
             true
         } else {
             false
         }
     }
 
+    /// Sets the store where the user specific data is read from and written to.
+    pub fn set_user_store(&mut self, store: Arc<dyn UserStore>) {
+        self.user_store = store;
+    }
+
+    /// Get the store of the user specific data.
+    pub fn get_user_store(&self) -> &dyn UserStore {
+        self.user_store.as_ref()
+    }
+
     pub fn get_database_dir(&self) -> &PathBuf {
         &self.database_dir
     }
@@ -80,25 +165,43 @@ impl Config {
         self.database_dir.join("autocorrect.json")
     }
 
-    /// Get file path of user defined Auto Correct file.
-    pub fn get_user_phonetic_autocorrect(&self) -> PathBuf {
-        self.user_dir.join("autocorrect.json")
+    /// Get the language of the input.
+    pub fn get_language(&self) -> Language {
+        self.language
     }
 
-    /// Get file path of user defined phonetic candidate selection file.
-    pub fn get_user_phonetic_selection_data(&self) -> PathBuf {
-        self.user_dir.join("phonetic-candidate-selection.json")
+    /// Set the language of the input.
+    ///
+    /// It selects the phonetic rules, the dictionary, the suffixes and the
+    /// Auto Correct entries of the methods.
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
     }
 
     pub fn get_suggestion_include_english(&self) -> bool {
         // Mutually exclusive
-        self.include_english && !self.ansi
+        self.include_english
+            && !self.get_ansi_encoding()
+            && self.input_purpose != InputPurpose::Search
     }
 
     pub fn set_suggestion_include_english(&mut self, include: bool) {
         self.include_english = include;
     }
 
+    /// Checks if Emoji suggestions are enabled.
+    pub fn get_suggestion_include_emoji(&self) -> bool {
+        // Mutually exclusive
+        self.include_emoji
+            && !self.get_ansi_encoding()
+            && self.input_purpose != InputPurpose::Search
+    }
+
+    /// Set the config's Emoji suggestion.
+    pub fn set_suggestion_include_emoji(&mut self, include: bool) {
+        self.include_emoji = include;
+    }
+
     pub fn get_phonetic_suggestion(&self) -> bool {
         self.phonetic_suggestion
     }
@@ -108,6 +211,19 @@ impl Config {
         self.phonetic_suggestion = phonetic_suggestion;
     }
 
+    /// Checks if the phonetic input is case-insensitive.
+    pub fn get_phonetic_case_insensitive(&self) -> bool {
+        self.phonetic_case_insensitive
+    }
+
+    /// Set the case-insensitive phonetic input.
+    ///
+    /// The typed text is converted in lower case, like `Tumi` as `tumi`. With
+    /// the phonetic suggestion, the reading of the text as typed is also suggested.
+    pub fn set_phonetic_case_insensitive(&mut self, case_insensitive: bool) {
+        self.phonetic_case_insensitive = case_insensitive;
+    }
+
     /// Get the config's fixed database.
     pub fn get_fixed_suggestion(&self) -> bool {
         self.fixed_suggestion
@@ -202,12 +318,28 @@ impl Config {
 
     /// Checks if ANSI encoding is enabled.
     pub fn get_ansi_encoding(&self) -> bool {
-        self.ansi
+        self.encoding != Encoding::Unicode
     }
 
     /// Set the ANSI encoding configuration.
+    ///
+    /// The Bijoy encoding is used when enabled.
     pub fn set_ansi_encoding(&mut self, ansi: bool) {
-        self.ansi = ansi;
+        self.encoding = if ansi {
+            Encoding::Bijoy
+        } else {
+            Encoding::Unicode
+        };
+    }
+
+    /// Get the encoding of the output.
+    pub fn get_encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Set the encoding of the output.
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
     }
 
     /// Get the config's smart quote configuration.
@@ -220,21 +352,385 @@ impl Config {
     pub fn set_smart_quote(&mut self, smart_quote: bool) {
         self.smart_quote = smart_quote;
     }
+
+    /// Checks if the smart quote uses the Bengali style quotation marks.
+    pub fn get_smart_quote_bengali(&self) -> bool {
+        self.smart_quote_bengali
+    }
+
+    /// Set the Bengali style quotation marks of the smart quote.
+    ///
+    /// The double quotation mark is converted into the single curved ones(‘, ’)
+    /// and the single quotation mark into the double curved ones(“, ”).
+    pub fn set_smart_quote_bengali(&mut self, bengali: bool) {
+        self.smart_quote_bengali = bengali;
+    }
+
+    /// Checks if the typed numbers are suggested with the digit grouping.
+    pub fn get_number_formatting(&self) -> bool {
+        self.number_formatting
+    }
+
+    /// Set the suggestion of the typed numbers with the Indian digit grouping,
+    /// like `1234567` as `১২,৩৪,৫৬৭`.
+    ///
+    /// Currency prefixes, `৳` or `tk`, and percent signs are also formatted.
+    pub fn set_number_formatting(&mut self, number_formatting: bool) {
+        self.number_formatting = number_formatting;
+    }
+
+    /// Checks if the typed numbers are suggested spelled in words.
+    pub fn get_number_words(&self) -> bool {
+        self.number_words
+    }
+
+    /// Set the suggestion of the typed numbers spelled in words, like `1250`
+    /// as `এক হাজার দুইশো পঞ্চাশ`, along with their ordinals.
+    pub fn set_number_words(&mut self, number_words: bool) {
+        self.number_words = number_words;
+    }
+
+    /// Get the normalisation form of the suggestions.
+    pub fn get_normalization(&self) -> Normalization {
+        self.normalization
+    }
+
+    /// Set the normalisation form of the suggestions.
+    ///
+    /// The suggestions are normalised before removing the duplicates, so the
+    /// canonically equivalent spellings are suggested once.
+    pub fn set_normalization(&mut self, normalization: Normalization) {
+        self.normalization = normalization;
+    }
+
+    /// Checks if the ISO 15919 romanisation of the best suggestion is suggested.
+    pub fn get_romanization(&self) -> bool {
+        // Mutually exclusive
+        self.romanization && !self.get_ansi_encoding()
+    }
+
+    /// Set the suggestion of the ISO 15919 romanisation of the best suggestion,
+    /// like `kathā` for `কথা`.
+    pub fn set_romanization(&mut self, romanization: bool) {
+        self.romanization = romanization;
+    }
+
+    /// Checks if `...` is suggested as `…`.
+    pub fn get_punctuation_ellipsis(&self) -> bool {
+        self.punctuation_ellipsis
+    }
+
+    /// Set the smart punctuation of `...` and `।।।` as `…`.
+    pub fn set_punctuation_ellipsis(&mut self, ellipsis: bool) {
+        self.punctuation_ellipsis = ellipsis;
+    }
+
+    /// Checks if `--` is suggested as `—`.
+    pub fn get_punctuation_dash(&self) -> bool {
+        self.punctuation_dash
+    }
+
+    /// Set the smart punctuation of `--` as `—`.
+    pub fn set_punctuation_dash(&mut self, dash: bool) {
+        self.punctuation_dash = dash;
+    }
+
+    /// Checks if a sentence-final `.` is suggested as `।`.
+    pub fn get_punctuation_dari(&self) -> bool {
+        self.punctuation_dari
+    }
+
+    /// Set the smart punctuation of a sentence-final `.` after a Bengali word as `।`.
+    ///
+    /// A `।` between digits is also suggested as the decimal point `.`.
+    pub fn set_punctuation_dari(&mut self, dari: bool) {
+        self.punctuation_dari = dari;
+    }
+
+    /// Checks if `।।` is suggested as `॥`.
+    pub fn get_punctuation_double_dari(&self) -> bool {
+        self.punctuation_double_dari
+    }
+
+    /// Set the smart punctuation of `।।` as `॥`.
+    pub fn set_punctuation_double_dari(&mut self, double_dari: bool) {
+        self.punctuation_double_dari = double_dari;
+    }
+
+    /// Checks if a space is put after the punctuation marks inside a word.
+    pub fn get_punctuation_spacing(&self) -> bool {
+        self.punctuation_spacing
+    }
+
+    /// Set the smart spacing between a punctuation mark and the following
+    /// Bengali word typed with it, like `আমি,তুমি` as `আমি, তুমি`.
+    pub fn set_punctuation_spacing(&mut self, spacing: bool) {
+        self.punctuation_spacing = spacing;
+    }
+
+    /// Get the number of candidates shown in a page.
+    ///
+    /// `0` means the candidates aren't paginated.
+    pub fn get_page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Set the number of candidates shown in a page, `0` disables pagination.
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size;
+    }
+
+    /// Get the key code which toggles the English mode.
+    ///
+    /// `0` means there is no such key.
+    pub fn get_english_toggle_key(&self) -> u16 {
+        self.english_toggle_key
+    }
+
+    /// Set the key code which toggles the English mode, `0` disables the key.
+    ///
+    /// It can be any key code the host sends, not only the ones of
+    /// [`keycodes`](crate::keycodes).
+    pub fn set_english_toggle_key(&mut self, key: u16) {
+        self.english_toggle_key = key;
+    }
+
+    /// Get the path of the word list used for the completions of the English mode.
+    pub fn get_english_word_list(&self) -> &Path {
+        &self.english_word_list
+    }
+
+    /// Sets the path of the word list used for the completions of the
+    /// English mode. The file contains one word per line.
+    ///
+    /// Returns `true` if the path exists.
+    pub fn set_english_word_list(&mut self, path: &str) -> bool {
+        if Path::new(path).exists() {
+            self.english_word_list = path.into();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Get the phonetic words which are suggested with the current date.
+    pub fn get_date_triggers(&self) -> &[String] {
+        &self.date_triggers
+    }
+
+    /// Set the phonetic words which are suggested with the current date,
+    /// like `aj` and `today`. They are matched case-insensitively.
+    pub fn set_date_triggers(&mut self, triggers: Vec<String>) {
+        self.date_triggers = triggers;
+    }
+
+    /// Get the phonetic words which are suggested with the current time.
+    pub fn get_time_triggers(&self) -> &[String] {
+        &self.time_triggers
+    }
+
+    /// Set the phonetic words which are suggested with the current time,
+    /// like `somoy`. They are matched case-insensitively.
+    pub fn set_time_triggers(&mut self, triggers: Vec<String>) {
+        self.time_triggers = triggers;
+    }
+
+    /// Sets the source of the current date and time.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Get the source of the current date and time.
+    pub fn get_clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Checks if the private input mode is enabled.
+    ///
+    /// In private mode, the learned user data is still used for making
+    /// suggestions but nothing new is learned or written to the user store.
+    pub fn get_private_mode(&self) -> bool {
+        self.private_mode
+    }
+
+    /// Set the private input mode.
+    pub fn set_private_mode(&mut self, private_mode: bool) {
+        self.private_mode = private_mode;
+    }
+
+    /// Get the purpose of the focused text field.
+    pub fn get_input_purpose(&self) -> InputPurpose {
+        self.input_purpose
+    }
+
+    /// Set the purpose of the focused text field.
+    pub fn set_input_purpose(&mut self, input_purpose: InputPurpose) {
+        self.input_purpose = input_purpose;
+    }
+}
+
+impl Config {
+    /// Load the config from the settings file at `path`.
+    ///
+    /// Options missing from the file get their default values and files
+    /// written by older versions are migrated to the current format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
+        let json = read_to_string(path).map_err(ConfigError::Io)?;
+        Self::from_json(&json)
+    }
+
+    /// Save the config into the settings file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        write(path, self.to_json()).map_err(ConfigError::Io)
+    }
+
+    /// Parse the config from the contents of a settings file.
+    pub fn from_json(json: &str) -> Result<Config, ConfigError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        let Value::Object(mut object) = value else {
+            return Err(ConfigError::Parse("expected a JSON object".to_owned()));
+        };
+
+        let version = match object.remove("version") {
+            Some(Value::Number(n)) => n.as_u64().ok_or_else(|| ConfigError::InvalidField {
+                field: "version".to_owned(),
+                reason: format!("invalid version number {n}"),
+            })?,
+            Some(v) => {
+                return Err(ConfigError::InvalidField {
+                    field: "version".to_owned(),
+                    reason: format!("expected a number, found {v}"),
+                })
+            }
+            None => CONFIG_VERSION,
+        };
+
+        let object = migrate(object, version)?;
+
+        let mut config: Config = serde_json::from_value(Value::Object(object.clone()))
+            .map_err(|e| invalid_field(&object, e))?;
+        config.validate()?;
+
+        if !config.user_dir.as_os_str().is_empty() {
+            config.user_store = Arc::new(FileStore::new(&config.user_dir));
+        }
+
+        Ok(config)
+    }
+
+    /// Serialize the config into the contents of a settings file.
+    pub fn to_json(&self) -> String {
+        let Value::Object(options) = serde_json::to_value(self).unwrap() else {
+            unreachable!()
+        };
+        let mut object = Map::new();
+        object.insert("version".to_owned(), CONFIG_VERSION.into());
+        object.extend(options);
+
+        serde_json::to_string_pretty(&object).unwrap()
+    }
+
+    /// Checks the paths of the config.
+    ///
+    /// The user directory isn't checked, as it may be created later by the host.
+    fn validate(&self) -> Result<(), ConfigError> {
+        // Identifiers of the registered methods aren't paths.
+        let layout = Path::new(&self.layout);
+        let is_path = layout.extension().is_some() || self.layout.contains(std::path::is_separator);
+
+        if is_path && !layout.exists() {
+            return Err(ConfigError::InvalidField {
+                field: "layout".to_owned(),
+                reason: format!("layout file {} doesn't exist", self.layout),
+            });
+        }
+
+        if !self.database_dir.as_os_str().is_empty() && !self.database_dir.exists() {
+            return Err(ConfigError::InvalidField {
+                field: "database_dir".to_owned(),
+                reason: format!("directory {} doesn't exist", self.database_dir.display()),
+            });
+        }
+
+        if !self.english_word_list.as_os_str().is_empty() && !self.english_word_list.exists() {
+            return Err(ConfigError::InvalidField {
+                field: "english_word_list".to_owned(),
+                reason: format!("file {} doesn't exist", self.english_word_list.display()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Migrate the options of a settings file written in the format `version`
+/// to the current format.
+fn migrate(object: Map<String, Value>, version: u64) -> Result<Map<String, Value>, ConfigError> {
+    match version {
+        CONFIG_VERSION => Ok(object),
+        _ => Err(ConfigError::UnsupportedVersion(version)),
+    }
+}
+
+/// Find out the option which made the deserialization of the `object` fail.
+fn invalid_field(object: &Map<String, Value>, error: serde_json::Error) -> ConfigError {
+    for (field, value) in object {
+        let mut single = Map::new();
+        single.insert(field.clone(), value.clone());
+
+        if let Err(e) = serde_json::from_value::<Config>(Value::Object(single)) {
+            return ConfigError::InvalidField {
+                field: field.clone(),
+                reason: e.to_string(),
+            };
+        }
+    }
+
+    ConfigError::Parse(error.to_string())
+}
+
+/// Error occurred while loading or saving a settings file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The settings file couldn't be read or written.
+    Io(io::Error),
+    /// The settings file isn't valid.
+    Parse(String),
+    /// The settings file was written in an unknown format version.
+    UnsupportedVersion(u64),
+    /// An option of the settings file has an invalid value.
+    InvalidField { field: String, reason: String },
 }
 
-pub fn get_user_data_dir() -> PathBuf {
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "{e}"),
+            ConfigError::Parse(e) => write!(f, "invalid settings file: {e}"),
+            ConfigError::UnsupportedVersion(v) => {
+                write!(f, "unsupported settings file version {v}")
+            }
+            ConfigError::InvalidField { field, reason } => {
+                write!(f, "invalid option `{field}`: {reason}")
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Returns the platform specific user data directory, if it can be determined.
+pub fn get_user_data_dir() -> Option<PathBuf> {
     var("XDG_DATA_HOME")
         .ok()
         .or_else(|| var("HOME").ok().map(|path| path + "/.local/share"))
         .map(|path| path + "/openbangla-keyboard")
         .or_else(|| {
             // Windows
-            var("localappdata")
-                .ok()
-                .map(|path| path + "\\OpenBangla")
+            var("localappdata").ok().map(|path| path + "\\OpenBangla")
         })
-        .unwrap()
-        .into()
+        .map(PathBuf::from)
 }
 
 #[cfg(test)]
@@ -263,13 +759,42 @@ pub fn get_fixed_method_defaults() -> Config {
     }
 }
 
+/// Types the `keys` in a new input session of the `method`.
+///
+/// An ongoing input session is finished first.
+#[cfg(test)]
+pub fn type_keys(
+    method: &mut impl crate::context::Method,
+    keys: &[u16],
+    data: &crate::data::Data,
+    config: &Config,
+) -> crate::suggestion::Suggestion {
+    if method.ongoing_input_session() {
+        method.finish_input_session();
+    }
+    keys.iter()
+        .map(|&key| method.get_suggestion(key, 0, 0, data, config))
+        .last()
+        .unwrap()
+}
+
 impl Default for Config {
     fn default() -> Self {
+        let user_dir = get_user_data_dir();
+        // Keep the user data in memory if there is no place to store it.
+        let user_store: Arc<dyn UserStore> = match &user_dir {
+            Some(dir) => Arc::new(FileStore::new(dir)),
+            None => Arc::new(MemoryStore::new()),
+        };
+
         Config {
             layout: Default::default(),
             database_dir: Default::default(),
-            user_dir: get_user_data_dir(),
+            user_dir: user_dir.unwrap_or_default(),
+            user_store,
+            language: Language::Bengali,
             include_english: false,
+            include_emoji: true,
             fixed_suggestion: false,
             fixed_vowel: false,
             fixed_chandra: false,
@@ -278,8 +803,27 @@ impl Default for Config {
             fixed_old_reph: false,
             fixed_kar_order: false,
             phonetic_suggestion: false,
-            ansi: false,
+            phonetic_case_insensitive: false,
+            encoding: Encoding::Unicode,
             smart_quote: true,
+            smart_quote_bengali: false,
+            number_formatting: false,
+            number_words: true,
+            normalization: Normalization::None,
+            romanization: false,
+            punctuation_ellipsis: false,
+            punctuation_dash: false,
+            punctuation_dari: false,
+            punctuation_double_dari: false,
+            punctuation_spacing: false,
+            page_size: 0,
+            english_toggle_key: 0,
+            english_word_list: PathBuf::new(),
+            date_triggers: vec!["aj".to_owned(), "today".to_owned(), "tarikh".to_owned()],
+            time_triggers: vec!["somoy".to_owned(), "now".to_owned()],
+            clock: Arc::new(SystemClock),
+            private_mode: false,
+            input_purpose: InputPurpose::Normal,
         }
     }
 }
@@ -293,12 +837,14 @@ mod tests {
     fn test_data_dir_linux() {
         assert_eq!(
             get_user_data_dir(),
-            PathBuf::from(var("HOME").unwrap() + "/.local/share/openbangla-keyboard")
+            Some(PathBuf::from(
+                var("HOME").unwrap() + "/.local/share/openbangla-keyboard"
+            ))
         );
         std::env::set_var("XDG_DATA_HOME", "/non/existent");
         assert_eq!(
             get_user_data_dir(),
-            PathBuf::from("/non/existent/openbangla-keyboard")
+            Some(PathBuf::from("/non/existent/openbangla-keyboard"))
         );
     }
 
@@ -307,7 +853,7 @@ mod tests {
     fn test_data_dir_windows() {
         assert_eq!(
             get_user_data_dir(),
-            PathBuf::from(var("localappdata").unwrap() + "\\OpenBangla")
+            Some(PathBuf::from(var("localappdata").unwrap() + "\\OpenBangla"))
         )
     }
 
@@ -321,6 +867,20 @@ mod tests {
 
         config.set_ansi_encoding(true);
         assert!(!config.get_suggestion_include_english());
+
+        assert!(!config.get_suggestion_include_emoji());
+        config.set_ansi_encoding(false);
+        assert!(config.get_suggestion_include_emoji());
+
+        config.set_romanization(true);
+        assert!(config.get_romanization());
+        config.set_ansi_encoding(true);
+        assert!(!config.get_romanization());
+        config.set_ansi_encoding(false);
+
+        config.set_input_purpose(InputPurpose::Search);
+        assert!(!config.get_suggestion_include_english());
+        assert!(!config.get_suggestion_include_emoji());
     }
 
     #[test]
@@ -340,4 +900,96 @@ mod tests {
         assert!(!config.set_database_dir("/non_existent"));
         assert!(config.set_database_dir(&format!("{}{}", env!("CARGO_MANIFEST_DIR"), "/data")));
     }
+
+    #[test]
+    fn test_settings_file() {
+        let mut config = get_fixed_method_defaults();
+        config.set_suggestion_include_english(true);
+        config.set_smart_quote(false);
+        config.set_language(Language::Assamese);
+
+        let json = config.to_json();
+        let loaded = Config::from_json(&json).unwrap();
+        assert_eq!(loaded.to_json(), json);
+        assert!(loaded.get_suggestion_include_english());
+        assert_eq!(loaded.get_language(), Language::Assamese);
+        assert!(!loaded.get_smart_quote());
+        assert!(loaded.get_fixed_automatic_vowel());
+        assert_eq!(loaded.get_layout_file_path(), config.get_layout_file_path());
+
+        let path = std::env::temp_dir().join(format!("riti-config-{}.json", std::process::id()));
+        config.save(&path).unwrap();
+        assert_eq!(Config::load(&path).unwrap().to_json(), json);
+        std::fs::remove_file(path).unwrap();
+
+        assert!(matches!(
+            Config::load("/non_existent/config.json"),
+            Err(ConfigError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_settings_file_defaults() {
+        let config = Config::from_json(r#"{"version": 1, "layout": "avro_phonetic"}"#).unwrap();
+        assert!(config.is_phonetic());
+        assert!(config.get_smart_quote());
+        assert!(config.get_suggestion_include_emoji());
+        assert!(!config.get_phonetic_suggestion());
+
+        // Without the version
+        let config = Config::from_json(r#"{"phonetic_suggestion": true}"#).unwrap();
+        assert!(config.get_phonetic_suggestion());
+    }
+
+    #[test]
+    fn test_settings_file_validation() {
+        let field = |json| match Config::from_json(json) {
+            Err(ConfigError::InvalidField { field, .. }) => field,
+            other => panic!("unexpected result: {other:?}"),
+        };
+
+        assert_eq!(
+            field(r#"{"version": 1, "fixed_numpad": "yes"}"#),
+            "fixed_numpad"
+        );
+        assert_eq!(
+            field(r#"{"smart_quote": true, "fixed_numpd": true}"#),
+            "fixed_numpd"
+        );
+        assert_eq!(
+            field(r#"{"layout": "/non_existent/Probhat.json"}"#),
+            "layout"
+        );
+        assert_eq!(field(r#"{"layout": "Probhat.json"}"#), "layout");
+        // Identifier of a registered method
+        let config = Config::from_json(r#"{"layout": "custom"}"#).unwrap();
+        assert_eq!(config.get_layout_file_path(), "custom");
+        assert_eq!(
+            field(r#"{"database_dir": "/non_existent"}"#),
+            "database_dir"
+        );
+        assert_eq!(
+            field(r#"{"english_word_list": "/non_existent/words.txt"}"#),
+            "english_word_list"
+        );
+        assert_eq!(field(r#"{"version": "1"}"#), "version");
+        assert_eq!(
+            field(r#"{"version": 1, "ansi_encoding": true}"#),
+            "ansi_encoding"
+        );
+
+        assert!(matches!(
+            Config::from_json(r#"{"version": 99}"#),
+            Err(ConfigError::UnsupportedVersion(99))
+        ));
+        assert!(matches!(
+            Config::from_json(r#"{"version": 0}"#),
+            Err(ConfigError::UnsupportedVersion(0))
+        ));
+        assert!(matches!(
+            Config::from_json("[]"),
+            Err(ConfigError::Parse(_))
+        ));
+        assert!(matches!(Config::from_json("{"), Err(ConfigError::Parse(_))));
+    }
 }
@@ -1,69 +1,423 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell, RefMut};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
 
+use crate::english::EnglishMethod;
 use crate::phonetic::method::PhoneticMethod;
+use crate::profile::{load_profiles, save_profiles, Profile, Profiles};
 use crate::suggestion::Suggestion;
 use crate::{config::Config, data::Data, fixed::method::FixedMethod};
 
+/// Number of the commits kept in the history of an input mode.
+const HISTORY_SIZE: usize = 100;
+
 /// Context handle used for libRiti IM APIs
 pub struct RitiContext {
     method: RefCell<Box<dyn Method>>,
+    // Method of the English mode.
+    english: RefCell<EnglishMethod>,
+    mode: Cell<InputMode>,
+    // The loaded methods which aren't active, keyed by their layout.
+    methods: HashMap<String, Box<dyn Method>>,
+    registry: MethodRegistry,
+    // The config in effect, with the active profile applied.
     config: Config,
+    // The config given by the host.
+    base_config: Config,
+    profiles: Profiles,
+    active_profile: Option<String>,
+    // All of the suggestions being paginated and the current page.
+    pages: RefCell<Option<(Suggestion, usize)>>,
+    // Texts of the candidates of the last suggestion.
+    candidates: RefCell<Vec<String>>,
+    // Committed candidates of each input mode, the latest last.
+    history: RefCell<[VecDeque<String>; 2]>,
     data: Data,
 }
 
 impl RitiContext {
     /// A new `RitiContext` instance.
     pub fn new_with_config(config: &Config) -> Self {
+        Self::new_with_registry(config, MethodRegistry::default())
+    }
+
+    /// A new `RitiContext` instance which can use the methods of the `registry`
+    /// besides the built-in ones.
+    pub fn new_with_registry(config: &Config, registry: MethodRegistry) -> Self {
         let config = config.to_owned();
-        let data = Data::new();
-        let method = RefCell::new(<dyn Method>::new(&config));
+        let data = Data::new_with_language(config.get_language());
+        let method = RefCell::new(registry.build(&config));
+        let profiles = load_profiles(config.get_user_store());
         RitiContext {
             method,
+            english: RefCell::new(EnglishMethod::new(&config)),
+            mode: Cell::new(InputMode::Bengali),
+            methods: HashMap::new(),
+            registry,
+            base_config: config.clone(),
             config,
+            profiles,
+            active_profile: None,
+            pages: RefCell::new(None),
+            candidates: RefCell::new(Vec::new()),
+            history: RefCell::new([VecDeque::new(), VecDeque::new()]),
             data,
         }
     }
 
     /// Get suggestion for key.
+    ///
+    /// If the candidates are paginated, the returned `Suggestion` contains the
+    /// page of the previously selected candidate and `selection` is relative
+    /// to the current page.
+    ///
+    /// The English toggle key of the config switches the input mode and
+    /// returns an empty suggestion.
     pub fn get_suggestion_for_key(&self, key: u16, modifier: u8, selection: u8) -> Suggestion {
-        self.method
-            .borrow_mut()
-            .get_suggestion(key, modifier, selection, &self.data, &self.config)
+        let toggle_key = self.config.get_english_toggle_key();
+
+        if toggle_key != 0 && key == toggle_key {
+            self.change_input_mode(match self.mode.get() {
+                InputMode::Bengali => InputMode::English,
+                InputMode::English => InputMode::Bengali,
+            });
+            return Suggestion::empty();
+        }
+
+        let selection = self
+            .absolute_index(selection.into())
+            .try_into()
+            .unwrap_or(u8::MAX);
+        let suggestion =
+            self.active_method()
+                .get_suggestion(key, modifier, selection, &self.data, &self.config);
+        self.paginate(suggestion)
     }
 
     /// A candidate of the suggestion list was committed.
     ///
-    /// `index`: index of the candidate.
+    /// `index`: index of the candidate, relative to the current page
+    /// if the candidates are paginated.
     ///
     /// This function will end the ongoing input session.
     pub fn candidate_committed(&self, index: usize) {
-        self.method
-            .borrow_mut()
+        let index = self.absolute_index(index);
+        self.pages.replace(None);
+        self.record_commit(index);
+        self.active_method()
             .candidate_committed(index, &self.config)
     }
 
+    /// Add the committed candidate of the `index` to the history of the input mode.
+    fn record_commit(&self, index: usize) {
+        let candidates = self.candidates.take();
+        let purpose = self.config.get_input_purpose();
+
+        // Nothing is recorded in the private mode or from a field without conversion.
+        if self.config.get_private_mode() || purpose.is_passthrough() {
+            return;
+        }
+
+        if let Some(text) = candidates.into_iter().nth(index) {
+            let history = &mut self.history.borrow_mut()[self.mode.get() as usize];
+            if history.len() == HISTORY_SIZE {
+                history.pop_front();
+            }
+            history.push_back(text);
+        }
+    }
+
+    /// Get the candidates committed in the input `mode`, the latest last.
+    ///
+    /// Only the candidates committed with [`candidate_committed()`](Self::candidate_committed)
+    /// are recorded, except in the private mode and in the fields without conversion.
+    pub fn get_commit_history(&self, mode: InputMode) -> Vec<String> {
+        self.history.borrow()[mode as usize]
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Switch to the input `mode`.
+    ///
+    /// The ongoing input session of the previous mode is finished, but the
+    /// state of its method is kept.
+    pub fn set_input_mode(&mut self, mode: InputMode) {
+        self.change_input_mode(mode);
+    }
+
+    /// Get the input mode.
+    pub fn get_input_mode(&self) -> InputMode {
+        self.mode.get()
+    }
+
+    fn change_input_mode(&self, mode: InputMode) {
+        if mode != self.mode.get() {
+            self.finish_input_session();
+            self.mode.set(mode);
+        }
+    }
+
+    /// The method of the input mode.
+    fn active_method(&self) -> RefMut<'_, dyn Method> {
+        match self.mode.get() {
+            InputMode::Bengali => RefMut::map(self.method.borrow_mut(), |method| method.as_mut()),
+            InputMode::English => RefMut::map(self.english.borrow_mut(), |method| method as _),
+        }
+    }
+
+    /// Show the next page of the candidates.
+    ///
+    /// Returns `None` if the candidates aren't paginated.
+    pub fn page_down(&self) -> Option<Suggestion> {
+        self.turn_page(|page| page + 1)
+    }
+
+    /// Show the previous page of the candidates.
+    ///
+    /// Returns `None` if the candidates aren't paginated.
+    pub fn page_up(&self) -> Option<Suggestion> {
+        self.turn_page(|page| page.saturating_sub(1))
+    }
+
+    fn turn_page(&self, turn: impl FnOnce(usize) -> usize) -> Option<Suggestion> {
+        let page_size = self.config.get_page_size();
+        let mut pages = self.pages.borrow_mut();
+        let (suggestion, page) = pages.as_mut()?;
+        let new = suggestion.page(turn(*page), page_size);
+        *page = new.current_page().unwrap_or_default();
+        Some(new)
+    }
+
+    /// Split the `suggestion` into pages, if the pagination is enabled,
+    /// and return the page of the previously selected candidate.
+    fn paginate(&self, suggestion: Suggestion) -> Suggestion {
+        let page_size = self.config.get_page_size();
+        self.candidates.replace(
+            suggestion
+                .get_suggestions()
+                .map(|suggestions| suggestions.into_iter().map(str::to_owned).collect())
+                .unwrap_or_default(),
+        );
+
+        if page_size == 0 || suggestion.is_lonely() {
+            self.pages.replace(None);
+            return suggestion;
+        }
+
+        let page = suggestion.previously_selected_index().unwrap_or_default() / page_size;
+        let current = suggestion.page(page, page_size);
+        self.pages.replace(Some((suggestion, page)));
+        current
+    }
+
+    /// Translate the page relative `index` into the index among all of the candidates.
+    fn absolute_index(&self, index: usize) -> usize {
+        match &*self.pages.borrow() {
+            Some((_, page)) => page * self.config.get_page_size() + index,
+            None => index,
+        }
+    }
+
     /// Update the suggestion making engine. This would also look for changes
     /// in layout selection and AutoCorrect database.
+    ///
+    /// The private input mode, the input purpose and the active profile
+    /// of the context are kept as they are.
+    ///
+    /// If the layout has been changed, the method of the previous layout is
+    /// kept loaded, see [`switch_method()`](Self::switch_method).
     pub fn update_engine(&mut self, config: &Config) {
+        let previous = self.config.get_layout_file_path().to_owned();
+        let layout_changed = self.config.layout_changed(config);
+        let private_mode = self.config.get_private_mode();
+        let input_purpose = self.config.get_input_purpose();
+
+        // Update the config
+        self.base_config = config.to_owned();
+        self.base_config.set_private_mode(private_mode);
+        self.base_config.set_input_purpose(input_purpose);
+        self.apply_active_profile();
+        self.pages.replace(None);
+        self.data.set_language(self.config.get_language());
+
         // If the layout file has been changed.
-        if self.config.layout_changed(config) {
-            self.method.replace(<dyn Method>::new(config));
+        if layout_changed {
+            let method = match self.methods.remove(config.get_layout_file_path()) {
+                Some(method) => method,
+                None => self.registry.build(&self.config),
+            };
+            self.activate(method, previous);
         } else {
-            self.method.borrow_mut().update_engine(config);
+            self.method.borrow_mut().update_engine(&self.config);
+        }
+        self.english.borrow_mut().update_engine(&self.config);
+    }
+
+    /// Load the method of the layout `id` without activating it, so that
+    /// switching to it is instant.
+    ///
+    /// `id` is the identifier of a registered method or the path of a fixed
+    /// method layout file. Returns `false` if it is neither.
+    pub fn load_method(&mut self, id: &str) -> bool {
+        if !self.registry.contains(id) && !Path::new(id).exists() {
+            return false;
         }
 
-        // Update the config
-        self.config = config.to_owned();
+        if id != self.config.get_layout_file_path() && !self.methods.contains_key(id) {
+            let mut config = self.config.clone();
+            config.set_layout_id(id);
+            self.methods
+                .insert(id.to_owned(), self.registry.build(&config));
+        }
+
+        true
+    }
+
+    /// Unload the method of the layout `id`, discarding its state.
+    ///
+    /// The active method can't be unloaded.
+    pub fn unload_method(&mut self, id: &str) {
+        self.methods.remove(id);
+    }
+
+    /// Switch to the loaded method of the layout `id`.
+    ///
+    /// The ongoing input session is finished and the previously active method
+    /// is kept loaded along with its state. Returns `false` if the method
+    /// of `id` isn't loaded, see [`load_method()`](Self::load_method).
+    pub fn switch_method(&mut self, id: &str) -> bool {
+        if id == self.config.get_layout_file_path() {
+            return true;
+        }
+
+        let Some(method) = self.methods.remove(id) else {
+            return false;
+        };
+        let previous = self.config.get_layout_file_path().to_owned();
+
+        self.base_config.set_layout_id(id);
+        self.apply_active_profile();
+        self.pages.replace(None);
+        self.activate(method, previous);
+
+        true
+    }
+
+    /// Make the `method` active and keep the previously active one,
+    /// of the layout `previous`, loaded.
+    fn activate(&mut self, mut method: Box<dyn Method>, previous: String) {
+        // The method may have missed the changes of the config.
+        method.update_engine(&self.config);
+        let mut previous_method = self.method.replace(method);
+        previous_method.finish_input_session();
+        self.methods.insert(previous, previous_method);
+    }
+
+    /// Enable or disable the private input mode.
+    ///
+    /// In private mode, nothing is learned from the user's input. The
+    /// previously learned data is still used for making suggestions.
+    /// It is intended to be toggled when the focus moves to a password
+    /// field or a private chat.
+    pub fn set_private_mode(&mut self, enabled: bool) {
+        self.base_config.set_private_mode(enabled);
+        self.config.set_private_mode(enabled);
+    }
+
+    /// Checks if the private input mode is enabled.
+    pub fn is_private_mode(&self) -> bool {
+        self.config.get_private_mode()
+    }
+
+    /// Set the purpose of the focused text field.
+    ///
+    /// The engine adjusts its behaviour according to the `purpose`,
+    /// see [`InputPurpose`] for details. The ongoing input session is
+    /// finished if the purpose is changed.
+    pub fn set_input_purpose(&mut self, purpose: InputPurpose) {
+        if purpose != self.config.get_input_purpose() {
+            self.finish_input_session();
+        }
+
+        self.base_config.set_input_purpose(purpose);
+        self.config.set_input_purpose(purpose);
+    }
+
+    /// Get the purpose of the focused text field.
+    pub fn get_input_purpose(&self) -> InputPurpose {
+        self.config.get_input_purpose()
+    }
+
+    /// Switch to the profile of the application `app_id`.
+    ///
+    /// The options of the application's profile override the config given to
+    /// the context. If the application has no profile, the config is used as it is.
+    pub fn set_active_profile(&mut self, app_id: &str) {
+        self.active_profile = Some(app_id.to_owned());
+        self.apply_active_profile();
+        self.pages.replace(None);
+        self.data.set_language(self.config.get_language());
+        self.method.borrow_mut().update_engine(&self.config);
+        self.english.borrow_mut().update_engine(&self.config);
+    }
+
+    /// Get the identifier of the application whose profile is active.
+    pub fn get_active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
+
+    /// Get the profile of the application `app_id`.
+    pub fn get_profile(&self, app_id: &str) -> Option<&Profile> {
+        self.profiles.get(app_id)
+    }
+
+    /// Set the profile of the application `app_id`.
+    ///
+    /// The profiles are saved in the user store.
+    pub fn set_profile(&mut self, app_id: &str, profile: Profile) {
+        self.profiles.insert(app_id.to_owned(), profile);
+        save_profiles(self.config.get_user_store(), &self.profiles);
+
+        if self.get_active_profile() == Some(app_id) {
+            self.set_active_profile(app_id);
+        }
+    }
+
+    /// Remove the profile of the application `app_id`.
+    pub fn remove_profile(&mut self, app_id: &str) {
+        if self.profiles.remove(app_id).is_some() {
+            save_profiles(self.config.get_user_store(), &self.profiles);
+
+            if self.get_active_profile() == Some(app_id) {
+                self.set_active_profile(app_id);
+            }
+        }
+    }
+
+    /// Recompute the config in effect from the host's config and the active profile.
+    fn apply_active_profile(&mut self) {
+        self.config = self.base_config.clone();
+
+        if let Some(profile) = self
+            .active_profile
+            .as_ref()
+            .and_then(|app_id| self.profiles.get(app_id))
+        {
+            profile.apply(&mut self.config);
+        }
     }
 
     /// Checks if there is an onging input session.         
     pub fn ongoing_input_session(&self) -> bool {
-        self.method.borrow().ongoing_input_session()
+        self.active_method().ongoing_input_session()
     }
 
     /// Finish the ongoing input session if any.
     pub fn finish_input_session(&self) {
-        self.method.borrow_mut().finish_input_session();
+        self.pages.replace(None);
+        self.candidates.take();
+        self.active_method().finish_input_session();
     }
 
     /// A BackSpace event.
@@ -76,13 +430,22 @@ impl RitiContext {
     /// If the internal buffer becomes empty, this function will
     /// end the ongoing input session.
     pub fn backspace_event(&self, ctrl: bool) -> Suggestion {
-        self.method
-            .borrow_mut()
-            .backspace_event(ctrl, &self.data, &self.config)
+        let suggestion = self
+            .active_method()
+            .backspace_event(ctrl, &self.data, &self.config);
+        self.paginate(suggestion)
     }
 }
 
-pub(crate) trait Method {
+/// An input method, which makes the suggestions from the key presses.
+///
+/// The methods are created by the [`MethodRegistry`] according to the layout
+/// of the config. The [`RitiContext`] owns the method and forwards the events
+/// of the host to it, along with the shared [`Data`] and the config in effect.
+pub trait Method {
+    /// Returns the suggestion for a key press.
+    ///
+    /// `selection` is the index of the previously selected candidate.
     fn get_suggestion(
         &mut self,
         key: u16,
@@ -91,23 +454,117 @@ pub(crate) trait Method {
         data: &Data,
         config: &Config,
     ) -> Suggestion;
+
+    /// The candidate of the `index` was committed, which ends the input session.
     fn candidate_committed(&mut self, index: usize, config: &Config);
+
+    /// The config was changed, but not the layout.
     fn update_engine(&mut self, config: &Config);
+
+    /// Checks if there is an ongoing input session.
     fn ongoing_input_session(&self) -> bool;
+
+    /// Finishes the ongoing input session, if any.
     fn finish_input_session(&mut self);
+
+    /// Returns the suggestion after a BackSpace key press.
+    ///
+    /// If `ctrl` is `true`, the whole word is deleted.
     fn backspace_event(&mut self, ctrl: bool, data: &Data, config: &Config) -> Suggestion;
 }
 
-impl dyn Method {
-    fn new(config: &Config) -> Box<dyn Method> {
-        if config.is_phonetic() {
-            Box::new(PhoneticMethod::new(config))
-        } else {
-            Box::new(FixedMethod::new(config))
+/// Constructor of a [`Method`] from the config.
+pub type MethodBuilder = Box<dyn Fn(&Config) -> Box<dyn Method>>;
+
+/// Registry of the input methods, keyed by the layout identifier.
+///
+/// The Avro Phonetic method is registered as `avro_phonetic`. A layout which
+/// isn't registered is taken as the path of a fixed method layout file.
+pub struct MethodRegistry {
+    methods: HashMap<String, MethodBuilder>,
+}
+
+impl MethodRegistry {
+    /// Registers the method which is built by the `builder` for the layout `id`.
+    ///
+    /// A previously registered method of the `id` is replaced. Use
+    /// [`Config::set_layout_id()`] to select the method.
+    pub fn register(&mut self, id: &str, builder: impl Fn(&Config) -> Box<dyn Method> + 'static) {
+        self.methods.insert(id.to_owned(), Box::new(builder));
+    }
+
+    /// Checks if a method is registered for the layout `id`.
+    pub fn contains(&self, id: &str) -> bool {
+        self.methods.contains_key(id)
+    }
+
+    /// Builds the method of the layout of the `config`.
+    fn build(&self, config: &Config) -> Box<dyn Method> {
+        match self.methods.get(config.get_layout_file_path()) {
+            Some(builder) => builder(config),
+            None => Box::new(FixedMethod::new(config)),
         }
     }
 }
 
+impl Default for MethodRegistry {
+    fn default() -> Self {
+        let mut registry = MethodRegistry {
+            methods: HashMap::new(),
+        };
+        registry.register("avro_phonetic", |config| {
+            Box::new(PhoneticMethod::new(config))
+        });
+        registry
+    }
+}
+
+/// Input mode of the context.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputMode {
+    /// Bengali, with the method of the layout.
+    #[default]
+    Bengali,
+    /// English. The typed text is passed through, with the completions from
+    /// the English word list of the config if there is one.
+    English,
+}
+
+/// Purpose of the text field which is receiving the input.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputPurpose {
+    /// A regular text field.
+    #[default]
+    Normal,
+    /// A URL field. The typed text is passed through without any conversion.
+    Url,
+    /// An Email address field. The typed text is passed through without any conversion.
+    Email,
+    /// A numeric field. Digits are produced in Bengali, the rest is passed through.
+    Number,
+    /// A numeric field. Digits are produced in ASCII, the rest is passed through.
+    AsciiNumber,
+    /// A password field. The typed text is passed through without any conversion
+    /// and nothing is learned.
+    Password,
+    /// A search box. Emoji and English candidates are not suggested.
+    Search,
+}
+
+impl InputPurpose {
+    /// Checks if the typed text should be passed through without any conversion.
+    pub(crate) fn is_passthrough(&self) -> bool {
+        matches!(self, Self::Url | Self::Email | Self::Password)
+    }
+
+    /// Checks if the field accepts numbers.
+    pub(crate) fn is_numeric(&self) -> bool {
+        matches!(self, Self::Number | Self::AsciiNumber)
+    }
+}
+
 /// Shift modifier key.
 ///
 /// Used by the [`get_suggestion_for_key()`](struct.RitiContext.html#method.get_suggestion_for_key) function.
@@ -119,10 +576,18 @@ pub const MODIFIER_ALT_GR: u8 = 1 << 1;
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use super::*;
     use crate::{
         config::{get_fixed_method_defaults, get_phonetic_method_defaults},
-        keycodes::{VC_E, VC_H, VC_L, VC_P},
+        encoding::Encoding,
+        keycodes::{
+            keycode_to_char, VC_A, VC_E, VC_H, VC_I, VC_L, VC_M, VC_P, VC_QUOTE, VC_R, VC_S, VC_T,
+        },
+        language::Language,
+        profile::Profile,
+        store::{MemoryStore, UserStore, PHONETIC_SELECTIONS},
     };
 
     #[test]
@@ -136,7 +601,7 @@ mod tests {
         context.get_suggestion_for_key(VC_L, 0, 0);
         let suggestion = context.get_suggestion_for_key(VC_P, 0, 0);
         context.finish_input_session();
-        assert_eq!(suggestion.get_suggestions(), ["হেল্প", "🆘"]);
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["হেল্প", "🆘"]);
 
         // Change the layout to Fixed layout
         let config = get_fixed_method_defaults();
@@ -147,7 +612,33 @@ mod tests {
         context.get_suggestion_for_key(VC_L, 0, 0);
         let suggestion = context.get_suggestion_for_key(VC_P, 0, 0);
         context.finish_input_session();
-        assert_eq!(suggestion.get_suggestions(), ["হীলপ"]);
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["হীলপ"]);
+    }
+
+    #[test]
+    fn test_language_change() {
+        let mut config = get_phonetic_method_defaults();
+        let mut context = RitiContext::new_with_config(&config);
+
+        let rati = |context: &RitiContext| {
+            let suggestion = [VC_R, VC_A, VC_T, VC_I]
+                .map(|key| context.get_suggestion_for_key(key, 0, 0))
+                .into_iter()
+                .last()
+                .unwrap();
+            context.finish_input_session();
+            suggestion
+                .get_candidates()
+                .unwrap()
+                .iter()
+                .map(|candidate| candidate.text.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rati(&context)[0], "রাতি");
+
+        config.set_language(Language::Assamese);
+        context.update_engine(&config);
+        assert_eq!(rati(&context), ["ৰাতি"]);
     }
 
     #[test]
@@ -159,6 +650,335 @@ mod tests {
         let context = RitiContext::new_with_config(&config);
 
         let suggestion = context.get_suggestion_for_key(VC_H, 0, 0);
-        assert_eq!(suggestion.get_suggestions(), ["হ"]);
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["হ"]);
+    }
+
+    #[test]
+    fn test_private_mode() {
+        let mut config = get_phonetic_method_defaults();
+        let store = Arc::new(MemoryStore::new());
+        store
+            .write(PHONETIC_SELECTIONS, r#"{"ami":"আমই"}"#.as_bytes())
+            .unwrap();
+        config.set_user_store(store.clone());
+        let mut context = RitiContext::new_with_config(&config);
+
+        context.set_private_mode(true);
+        assert!(context.is_private_mode());
+
+        // The private mode survives engine updates.
+        context.update_engine(&config);
+        assert!(context.is_private_mode());
+
+        // Learned data is still used.
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.previously_selected_index().unwrap(), 1);
+
+        // But nothing new is learned.
+        context.candidate_committed(2);
+        assert_eq!(
+            store.read(PHONETIC_SELECTIONS).unwrap(),
+            r#"{"ami":"আমই"}"#.as_bytes()
+        );
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.previously_selected_index().unwrap(), 1);
+        context.finish_input_session();
+
+        context.set_private_mode(false);
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        context.get_suggestion_for_key(VC_I, 0, 0);
+        context.candidate_committed(2);
+        assert_eq!(
+            store.read(PHONETIC_SELECTIONS).unwrap(),
+            r#"{"ami":"অমি"}"#.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_input_purpose_change() {
+        let config = get_phonetic_method_defaults();
+        let mut context = RitiContext::new_with_config(&config);
+
+        for key in [VC_QUOTE, VC_A, VC_M] {
+            context.get_suggestion_for_key(key, 0, 0);
+        }
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“আমি");
+        context.candidate_committed(0);
+
+        let suggestion = context.get_suggestion_for_key(VC_QUOTE, 0, 0);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "”");
+
+        // The focus moved to another field, so the quotation isn't closed there.
+        context.set_input_purpose(InputPurpose::Url);
+        assert!(!context.ongoing_input_session());
+        context.set_input_purpose(InputPurpose::Normal);
+        let suggestion = context.get_suggestion_for_key(VC_QUOTE, 0, 0);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“");
+    }
+
+    #[test]
+    fn test_profiles() {
+        let mut config = get_phonetic_method_defaults();
+        config.set_user_store(Arc::new(MemoryStore::new()));
+        let mut context = RitiContext::new_with_config(&config);
+
+        let type_smile = |context: &RitiContext| {
+            context.finish_input_session();
+            for key in [VC_S, VC_M, VC_I, VC_L] {
+                context.get_suggestion_for_key(key, 0, 0);
+            }
+            context.get_suggestion_for_key(VC_E, 0, 0)
+        };
+
+        context.set_profile(
+            "editor",
+            Profile {
+                include_emoji: Some(false),
+                ..Default::default()
+            },
+        );
+
+        context.set_active_profile("editor");
+        assert_eq!(context.get_active_profile(), Some("editor"));
+        assert_eq!(type_smile(&context).get_suggestions().unwrap(), ["স্মিলে"]);
+
+        // The profile survives engine updates.
+        context.update_engine(&config);
+        assert_eq!(type_smile(&context).get_suggestions().unwrap(), ["স্মিলে"]);
+
+        // An application without any profile.
+        context.set_active_profile("chat");
+        assert_eq!(
+            type_smile(&context).get_suggestions().unwrap(),
+            ["😀", "😄", "স্মিলে"]
+        );
+
+        // The profile of the active application takes effect when it's set.
+        context.set_profile(
+            "chat",
+            Profile {
+                page_size: Some(2),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            type_smile(&context).get_suggestions().unwrap(),
+            ["😀", "😄"]
+        );
+        context.remove_profile("chat");
+
+        // Profiles are persisted.
+        let mut context = RitiContext::new_with_config(&config);
+        assert_eq!(
+            context.get_profile("editor").unwrap().include_emoji,
+            Some(false)
+        );
+        context.set_active_profile("editor");
+        assert_eq!(type_smile(&context).get_suggestions().unwrap(), ["স্মিলে"]);
+
+        context.remove_profile("editor");
+        assert!(context.get_profile("editor").is_none());
+        assert_eq!(
+            type_smile(&context).get_suggestions().unwrap(),
+            ["😀", "😄", "স্মিলে"]
+        );
+    }
+
+    #[test]
+    fn test_pagination() {
+        let mut config = get_phonetic_method_defaults();
+        let store = Arc::new(MemoryStore::new());
+        config.set_user_store(store.clone());
+        config.set_page_size(2);
+        let context = RitiContext::new_with_config(&config);
+
+        // Not paginated yet.
+        assert!(context.page_down().is_none());
+
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["আমি", "আমই"]);
+        assert_eq!(suggestion.current_page(), Some(0));
+        assert_eq!(suggestion.total_len(), 4);
+
+        let suggestion = context.page_down().unwrap();
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["অমি", "এমই"]);
+        assert_eq!(suggestion.current_page(), Some(1));
+        assert_eq!(suggestion.previously_selected_index(), Some(0));
+
+        // Stays at the last page.
+        let suggestion = context.page_down().unwrap();
+        assert_eq!(suggestion.current_page(), Some(1));
+
+        let suggestion = context.page_up().unwrap();
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["আমি", "আমই"]);
+        let suggestion = context.page_up().unwrap();
+        assert_eq!(suggestion.current_page(), Some(0));
+
+        // The index of a committed candidate is relative to the page.
+        context.page_down();
+        context.candidate_committed(0);
+        assert_eq!(
+            store.read(PHONETIC_SELECTIONS).unwrap(),
+            r#"{"ami":"অমি"}"#.as_bytes()
+        );
+        assert!(context.page_down().is_none());
+
+        // The page of the previously selected candidate is shown.
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.current_page(), Some(1));
+        assert_eq!(suggestion.previously_selected_index(), Some(0));
+    }
+
+    /// A method which suggests the typed text in upper case.
+    #[derive(Default)]
+    struct UpperCase(String);
+
+    impl Method for UpperCase {
+        fn get_suggestion(&mut self, key: u16, _: u8, _: u8, _: &Data, _: &Config) -> Suggestion {
+            self.0.push(keycode_to_char(key).to_ascii_uppercase());
+            Suggestion::new_lonely(self.0.clone(), Encoding::Unicode)
+        }
+
+        fn candidate_committed(&mut self, _: usize, _: &Config) {
+            self.0.clear();
+        }
+
+        fn update_engine(&mut self, _: &Config) {}
+
+        fn ongoing_input_session(&self) -> bool {
+            !self.0.is_empty()
+        }
+
+        fn finish_input_session(&mut self) {
+            self.0.clear();
+        }
+
+        fn backspace_event(&mut self, _: bool, _: &Data, _: &Config) -> Suggestion {
+            self.0.pop();
+            Suggestion::new_lonely(self.0.clone(), Encoding::Unicode)
+        }
+    }
+
+    #[test]
+    fn test_method_registry() {
+        let mut registry = MethodRegistry::default();
+        assert!(registry.contains("avro_phonetic"));
+        registry.register("upper_case", |_| Box::<UpperCase>::default());
+        assert!(registry.contains("upper_case"));
+
+        let mut config = get_phonetic_method_defaults();
+        config.set_layout_id("upper_case");
+        let mut context = RitiContext::new_with_registry(&config, registry);
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_M, 0, 0);
+        assert_eq!(suggestion.get_lonely_suggestion(), Some("AM"));
+        let suggestion = context.backspace_event(false);
+        assert_eq!(suggestion.get_lonely_suggestion(), Some("A"));
+        context.finish_input_session();
+        assert!(!context.ongoing_input_session());
+
+        // The built-in methods are still available.
+        context.update_engine(&get_phonetic_method_defaults());
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "আমি");
+    }
+
+    #[test]
+    fn test_switch_method() {
+        let fixed = get_fixed_method_defaults();
+        let probhat = fixed.get_layout_file_path();
+        let mut context = RitiContext::new_with_config(&get_phonetic_method_defaults());
+
+        assert!(!context.switch_method(probhat));
+        assert!(!context.load_method("non_existent"));
+        assert!(context.load_method(probhat));
+        assert!(context.load_method("avro_phonetic"));
+
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        assert!(context.switch_method(probhat));
+        assert!(!context.ongoing_input_session());
+        assert_eq!(context.config.get_layout_file_path(), probhat);
+        let suggestion = context.get_suggestion_for_key(VC_A, 0, 0);
+        assert_eq!(suggestion.get_lonely_suggestion(), Some("া"));
+
+        // The previous method is kept loaded.
+        assert!(context.switch_method("avro_phonetic"));
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_I, 0, 0);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "আমি");
+
+        // Changing the layout keeps the method loaded too.
+        context.update_engine(&fixed);
+        assert!(context.switch_method("avro_phonetic"));
+        context.unload_method(probhat);
+        assert!(!context.switch_method(probhat));
+    }
+
+    #[test]
+    fn test_english_mode() {
+        // A key code riti doesn't know, like F12 of the host.
+        const TOGGLE_KEY: u16 = 0xFFC9;
+        let mut config = get_phonetic_method_defaults();
+        config.set_english_toggle_key(TOGGLE_KEY);
+        config.set_user_store(Arc::new(MemoryStore::new()));
+        let mut context = RitiContext::new_with_config(&config);
+
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        assert!(context.get_suggestion_for_key(TOGGLE_KEY, 0, 0).is_empty());
+        assert_eq!(context.get_input_mode(), InputMode::English);
+        assert!(!context.ongoing_input_session());
+
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_M, 0, 0);
+        assert_eq!(suggestion.get_lonely_suggestion(), Some("am"));
+        context.finish_input_session();
+
+        context.get_suggestion_for_key(TOGGLE_KEY, 0, 0);
+        assert_eq!(context.get_input_mode(), InputMode::Bengali);
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.get_suggestion_for_key(VC_M, 0, 0);
+        context.get_suggestion_for_key(VC_I, 0, 0);
+        context.candidate_committed(0);
+
+        // Nothing is recorded in the private mode.
+        context.set_private_mode(true);
+        context.get_suggestion_for_key(VC_A, 0, 0);
+        context.candidate_committed(0);
+        context.set_private_mode(false);
+
+        context.set_input_mode(InputMode::English);
+        let dir = std::env::temp_dir().join(format!("riti-english-mode-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("words.txt");
+        std::fs::write(&path, "help\nhello\n").unwrap();
+        assert!(config.set_english_word_list(path.to_str().unwrap()));
+        context.update_engine(&config);
+
+        context.get_suggestion_for_key(VC_H, 0, 0);
+        let suggestion = context.get_suggestion_for_key(VC_E, 0, 0);
+        assert_eq!(
+            suggestion.get_suggestions().unwrap(),
+            ["he", "hello", "help"]
+        );
+        context.candidate_committed(2);
+        assert_eq!(context.get_input_mode(), InputMode::English);
+
+        assert_eq!(context.get_commit_history(InputMode::Bengali), ["আমি"]);
+        assert_eq!(context.get_commit_history(InputMode::English), ["help"]);
+
+        std::fs::remove_dir_all(dir).unwrap();
     }
 }
@@ -3,49 +3,174 @@ use std::collections::HashMap;
 use ahash::RandomState;
 use emojicon::{BengaliEmoji, Emojicon};
 
+use crate::language::Language;
+use crate::normalization::{normalize, Normalization};
+
 /// Data which is shared between the methods.
-pub(crate) struct Data {
+///
+/// It contains the bundled suffix and Auto Correct dictionaries of the
+/// language and the emoji databases. An instance is owned by the
+/// [`RitiContext`](crate::context::RitiContext) and lent to the
+/// [`Method`](crate::context::Method).
+pub struct Data {
+    language: Language,
     suffix: HashMap<String, String, RandomState>,
     autocorrect: HashMap<String, String, RandomState>,
+    // Sorted words of the bundled dictionary. It's empty for Bengali, whose
+    // dictionary is provided by the `upodesh`.
+    words: Vec<String>,
+    // Words of the bundled dictionary by their folded spellings.
+    spellings: HashMap<String, Vec<String>, RandomState>,
     emojicon: Emojicon,
     bengali_emoji: BengaliEmoji,
 }
 
 impl Data {
     pub(crate) fn new() -> Data {
-        Data {
-            suffix: serde_json::from_slice(include_bytes!("../data/suffix.json")).unwrap(),
-            autocorrect: serde_json::from_slice(include_bytes!("../data/autocorrect.json")).unwrap(),
+        Data::new_with_language(Language::Bengali)
+    }
+
+    pub(crate) fn new_with_language(language: Language) -> Data {
+        let mut data = Data {
+            language,
+            suffix: HashMap::default(),
+            autocorrect: HashMap::default(),
+            words: Vec::new(),
+            spellings: HashMap::default(),
             emojicon: Emojicon::new(),
             bengali_emoji: BengaliEmoji::new(),
+        };
+        data.load(language);
+        data
+    }
+
+    /// Get the language of the data.
+    pub fn get_language(&self) -> Language {
+        self.language
+    }
+
+    /// Switch the data to the `language`, if it differs.
+    pub(crate) fn set_language(&mut self, language: Language) {
+        if self.language != language {
+            self.load(language);
         }
     }
 
-    pub(crate) fn find_suffix(&self, string: &str) -> Option<&str> {
+    /// Load the bundled dictionaries of the `language`.
+    fn load(&mut self, language: Language) {
+        let (suffix, autocorrect, words): (&[u8], &[u8], &[u8]) = match language {
+            Language::Bengali => (
+                include_bytes!("../data/suffix.json"),
+                include_bytes!("../data/autocorrect.json"),
+                b"[]",
+            ),
+            Language::Assamese => (
+                include_bytes!("../data/assamese/suffix.json"),
+                include_bytes!("../data/assamese/autocorrect.json"),
+                include_bytes!("../data/assamese/dictionary.json"),
+            ),
+        };
+
+        self.language = language;
+        self.suffix = serde_json::from_slice(suffix).unwrap();
+        self.autocorrect = serde_json::from_slice(autocorrect).unwrap();
+        self.words = serde_json::from_slice(words).unwrap();
+        self.words.sort_unstable();
+        self.spellings.clear();
+        for word in &self.words {
+            self.spellings
+                .entry(fold(word))
+                .or_default()
+                .push(word.clone());
+        }
+    }
+
+    /// Search for the Bengali suffix of the phonetic `string`.
+    pub fn find_suffix(&self, string: &str) -> Option<&str> {
         self.suffix.get(string).map(String::as_str)
     }
 
     /// Search for a `term` in the AutoCorrect dictionary.
-    pub(crate) fn search_corrected(&self, term: &str) -> Option<&str> {
+    pub fn search_corrected(&self, term: &str) -> Option<&str> {
         self.autocorrect.get(term).map(String::as_str)
     }
 
-    pub(crate) fn get_emoji_by_emoticon(&self, emoticon: &str) -> Option<&str> {
+    /// Search for the words of the bundled dictionary which are spelled alike
+    /// the phonetic transliteration `phonetic`.
+    ///
+    /// The letters which sound the same, like `শ`, `ষ` and `স`, and the short
+    /// and long vowels aren't distinguished.
+    pub fn search_dictionary(&self, phonetic: &str) -> &[String] {
+        self.spellings
+            .get(&fold(phonetic))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Search for the words of the bundled dictionary which complete the
+    /// `prefix` with at most `extra` more characters.
+    pub fn complete_word(&self, prefix: &str, extra: usize) -> Vec<String> {
+        let start = self.words.partition_point(|word| word.as_str() < prefix);
+
+        self.words[start..]
+            .iter()
+            .take_while(|word| word.starts_with(prefix))
+            .filter(|word| (1..=extra).contains(&word[prefix.len()..].chars().count()))
+            .cloned()
+            .collect()
+    }
+
+    /// Search for the emoji of an `emoticon`, like `:)`.
+    pub fn get_emoji_by_emoticon(&self, emoticon: &str) -> Option<&str> {
         self.emojicon.get_by_emoticon(emoticon)
     }
 
-    pub(crate) fn get_emoji_by_name(&self, name: &str) -> Option<impl Iterator<Item = &str>> {
+    /// Search for the emojis of an English `name`.
+    pub fn get_emoji_by_name(&self, name: &str) -> Option<impl Iterator<Item = &str>> {
         self.emojicon.get_by_name(name)
     }
 
-    pub(crate) fn get_emoji_by_bengali(&self, name: &str) -> Option<impl Iterator<Item = &str>> {
+    /// Search for the emojis of a Bengali `name`.
+    pub fn get_emoji_by_bengali(&self, name: &str) -> Option<impl Iterator<Item = &str>> {
         self.bengali_emoji.get(name)
     }
 }
 
+/// Folds the spelling of the `word`, so that the words which are pronounced
+/// alike in Assamese are the same.
+fn fold(word: &str) -> String {
+    let word = normalize(word, Normalization::Bangla);
+    let mut folded = String::with_capacity(word.len());
+
+    for c in word.chars() {
+        match c {
+            'ী' => folded.push('ি'),
+            'ূ' => folded.push('ু'),
+            'ঈ' => folded.push('ই'),
+            'ঊ' => folded.push('উ'),
+            'চ' | 'ছ' | 'শ' | 'ষ' => folded.push('স'),
+            'ঝ' | 'য' => folded.push('জ'),
+            'ণ' => folded.push('ন'),
+            'ৎ' => folded.push('ত'),
+            'ৱ' => {
+                // The `w` is typed with the vowel before it, like `jowa` for `যোৱা`.
+                if folded.ends_with(['ো', 'ু']) {
+                    folded.pop();
+                }
+                folded.push('ৱ');
+            }
+            'ঁ' => (),
+            c => folded.push(c),
+        }
+    }
+
+    folded
+}
+
 #[cfg(test)]
 mod tests {
     use super::Data;
+    use crate::language::Language;
 
     #[test]
     fn test_suffix() {
@@ -63,4 +188,25 @@ mod tests {
         assert_eq!(db.search_corrected("academy"), Some("oZakaDemi"));
         assert_eq!(db.search_corrected("\\nai\\"), None);
     }
+
+    #[test]
+    fn test_assamese() {
+        let mut db = Data::new();
+        assert!(db.search_dictionary("ঘৰ").is_empty());
+
+        db.set_language(Language::Assamese);
+        assert_eq!(db.get_language(), Language::Assamese);
+        assert_eq!(db.find_suffix("bor"), Some("বোৰ"));
+        assert_eq!(db.find_suffix("gulo"), None);
+        assert_eq!(db.search_corrected("guwahati"), Some("guwahaTee"));
+        assert_eq!(db.search_dictionary("ঘৰ"), ["ঘৰ"]);
+        assert_eq!(db.search_dictionary("অসমিয়া"), ["অসমী\u{09DF}া"]);
+        assert_eq!(db.search_dictionary("জৱা"), ["যোৱা"]);
+        assert_eq!(db.search_dictionary("সুৱালি"), ["ছোৱালী"]);
+        assert_eq!(db.complete_word("অসম", 1), Vec::<String>::new());
+        assert_eq!(db.complete_word("অসম", 5), ["অসমী\u{09DF}া"]);
+
+        db.set_language(Language::Bengali);
+        assert_eq!(db.find_suffix("gulo"), Some("গুলো"));
+    }
 }
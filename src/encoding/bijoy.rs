@@ -0,0 +1,476 @@
+//! Bijoy 2000 encoding.
+//!
+//! The Unicode text is encoded by the `poriborton` crate. The decoding table
+//! is its encoding table inverted, along with the glyphs of the kars and the
+//! special forms it produces.
+//!
+//! The kars `ি`, `ে` and `ৈ` are written before their letter and the reph after
+//! it in the Bijoy encoding, so they are reordered by the decoding.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use poriborton::bijoy2000::unicode_to_bijoy;
+
+use super::{substitute, Conversion, Converter};
+use crate::normalization::{normalize, Normalization};
+use crate::utility::push_checked;
+
+/// Glyphs of the Bijoy encoding and their Unicode text.
+#[rustfmt::skip]
+const GLYPHS: [(&str, &str); 287] = [
+    ("0", "০"),
+    ("1", "১"),
+    ("2", "২"),
+    ("3", "৩"),
+    ("4", "৪"),
+    ("5", "৫"),
+    ("6", "৬"),
+    ("7", "৭"),
+    ("8", "৮"),
+    ("9", "৯"),
+    ("r", "ৎ"),
+    ("u", "ঁ"),
+    ("s", "ং"),
+    ("t", "ঃ"),
+    ("$", "৳"),
+    ("Ò", "“"),
+    ("Ó", "”"),
+    ("Ô", "‘"),
+    ("Õ", "’"),
+    ("A", "অ"),
+    ("Av", "আ"),
+    ("B", "ই"),
+    ("C", "ঈ"),
+    ("D", "উ"),
+    ("E", "ঊ"),
+    ("F", "ঋ"),
+    ("G", "এ"),
+    ("H", "ঐ"),
+    ("I", "ও"),
+    ("J", "ঔ"),
+    ("K", "ক"),
+    ("L", "খ"),
+    ("M", "গ"),
+    ("N", "ঘ"),
+    ("O", "ঙ"),
+    ("P", "চ"),
+    ("Q", "ছ"),
+    ("R", "জ"),
+    ("S", "ঝ"),
+    ("T", "ঞ"),
+    ("U", "ট"),
+    ("V", "ঠ"),
+    ("W", "ড"),
+    ("X", "ঢ"),
+    ("Y", "ণ"),
+    ("Z", "ত"),
+    ("_", "থ"),
+    ("`", "দ"),
+    ("a", "ধ"),
+    ("b", "ন"),
+    ("c", "প"),
+    ("d", "ফ"),
+    ("e", "ব"),
+    ("f", "ভ"),
+    ("g", "ম"),
+    ("h", "য"),
+    ("i", "র"),
+    ("j", "ল"),
+    ("k", "শ"),
+    ("l", "ষ"),
+    ("m", "স"),
+    ("n", "হ"),
+    ("p", "ঢ়"),
+    ("o", "ড়"),
+    ("q", "য়"),
+    ("¯cÖ", "স্প্র"),
+    ("¯¿", "স্ত্র"),
+    ("¯—¡", "স্ত্ব"),
+    ("÷ª", "স্ট্র"),
+    ("¯Œ", "স্ক্র"),
+    ("®cÖ", "ষ্প্র"),
+    ("óª", "ষ্ট্র"),
+    ("®Œ", "ষ্ক্র"),
+    ("®‹¡", "ষ্ক্ব"),
+    ("k−", "শ্ল"),
+    ("¤£", "ম্ভ্র"),
+    ("¤^ª", "ম্ব্র"),
+    ("¤cÖ", "ম্প্র"),
+    ("Üª", "ন্ধ্র"),
+    ("›`ª", "ন্দ্র"),
+    ("›Ø", "ন্দ্ব"),
+    ("š’ª", "ন্থ্র"),
+    ("š¿", "ন্ত্র"),
+    ("š—¡", "ন্ত্ব"),
+    ("Ûª", "ন্ড্র"),
+    ("›Uª", "ন্ট্র"),
+    ("Ý", "ন্স"),
+    ("™£", "দ্ভ্র"),
+    ("Ï¡", "দ্দ্ব"),
+    ("Ë«", "ত্ত্র"),
+    ("Ë¡", "ত্ত্ব"),
+    ("Êª", "ণ্ড্র"),
+    ("¾¡", "জ্জ্ব"),
+    ("”Qª", "চ্ছ্র"),
+    ("”Q¡", "চ্ছ্ব"),
+    ("•Nª", "ঙ্ঘ্র"),
+    ("•¶", "ঙ্ক্ষ"),
+    ("»ª", "গ্ধ্র"),
+    ("²", "ক্ষ্ম"),
+    ("¶¡", "ক্ষ্ব"),
+    ("¶è", "ক্ষ্ণ"),
+    ("³«", "ক্ত্র"),
+    ("±ª", "ক্ট্র"),
+    ("nª", "হ্র"),
+    ("þ", "হ্ম"),
+    ("nŸ", "হ্ব"),
+    ("ý", "হ্ন"),
+    ("nœ", "হ্ণ"),
+    ("n¬", "হ্ল"),
+    ("¯¬", "স্ল"),
+    ("mª", "স্র"),
+    ("¯§", "স্ম"),
+    ("¯^", "স্ব"),
+    ("ù", "স্ফ"),
+    ("¯c", "স্প"),
+    ("¯c−", "স্প্ল"),
+    ("ø", "স্ন"),
+    ("¯’", "স্থ"),
+    ("¯—", "স্ত"),
+    ("÷", "স্ট"),
+    ("ö", "স্খ"),
+    ("¯‹", "স্ক"),
+    ("®§", "ষ্ম"),
+    ("®^", "ষ্ব"),
+    ("õ", "ষ্ফ"),
+    ("®c", "ষ্প"),
+    ("ò", "ষ্ণ"),
+    ("ò¡", "ষ্ণ্ব"),
+    ("ô", "ষ্ঠ"),
+    ("ó", "ষ্ট"),
+    ("®‹", "ষ্ক"),
+    ("kª", "শ্র"),
+    ("k¥", "শ্ম"),
+    ("k¦", "শ্ব"),
+    ("kœ", "শ্ন"),
+    ("ñ", "শ্ছ"),
+    ("ð", "শ্চ"),
+    ("j−", "ল্ল"),
+    ("j¥", "ল্ম"),
+    ("j¡", "ল্ব"),
+    ("í", "ল্প"),
+    ("ì", "ল্ড"),
+    ("ë", "ল্ট"),
+    ("ê", "ল্গ"),
+    ("é", "ল্ক"),
+    ("j¢", "ল্ভ"),
+    ("î", "ল্ফ"),
+    ("¤¬", "ম্ল"),
+    ("gª", "ম্র"),
+    ("¤§", "ম্ম"),
+    ("¤¢", "ম্ভ"),
+    ("¤^", "ম্ব"),
+    ("ç", "ম্ফ"),
+    ("¤c", "ম্প"),
+    ("æ", "ম্ন"),
+    ("å", "ভ্র"),
+    ("f¡", "ভ্ব"),
+    ("f¬", "ভ্ল"),
+    ("eª", "ব্র"),
+    ("eŸ", "ব্ব"),
+    ("ä", "ব্ধ"),
+    ("ã", "ব্দ"),
+    ("â", "ব্জ"),
+    ("e\u{AD}", "ব্ল"),
+    ("d¬", "ফ্ল"),
+    ("d«", "ফ্র"),
+    ("á", "প্স"),
+    ("cÖ", "প্র"),
+    ("à", "প্প"),
+    ("c\u{AD}", "প্ল"),
+    ("cœ", "প্ন"),
+    ("ß", "প্ত"),
+    ("Þ", "প্ট"),
+    ("b¥", "ন্ম"),
+    ("š^", "ন্ব"),
+    ("bœ", "ন্ন"),
+    ("›n", "ন্হ"),
+    ("Ü", "ন্ধ"),
+    ("›`", "ন্দ"),
+    ("š’", "ন্থ"),
+    ("š—", "ন্ত"),
+    ("Û", "ন্ড"),
+    ("Ú", "ন্ঠ"),
+    ("›U", "ন্ট"),
+    ("aª", "ধ্র"),
+    ("a¥", "ধ্ম"),
+    ("aŸ", "ধ্ব"),
+    ("aœ", "ধ্ন"),
+    ("`ª", "দ্র"),
+    ("Ù", "দ্ম"),
+    ("™¢", "দ্ভ"),
+    ("Ø", "দ্ব"),
+    ("×", "দ্ধ"),
+    ("Ï", "দ্দ"),
+    ("™N", "দ্ঘ"),
+    ("˜M", "দ্গ"),
+    ("_ª", "থ্র"),
+    ("_¡", "থ্ব"),
+    ("Î", "ত্র"),
+    ("Í", "ত্ম"),
+    ("Z¡", "ত্ব"),
+    ("Zœ", "ত্ন"),
+    ("Ì", "ত্থ"),
+    ("Ë", "ত্ত"),
+    ("Y¥", "ণ্ম"),
+    ("Y¡", "ণ্ব"),
+    ("Yè", "ণ্ণ"),
+    ("YX", "ণ্ঢ"),
+    ("Ê", "ণ্ড"),
+    ("É", "ণ্ঠ"),
+    ("È", "ণ্ট"),
+    ("Xª", "ঢ্র"),
+    ("Wª", "ড্র"),
+    ("W¡", "ড্ব"),
+    ("Ç", "ড্ড"),
+    ("W¥", "ড্ম"),
+    ("Û¡", "ন্ড্ব"),
+    ("Uª", "ট্র"),
+    ("U¥", "ট্ম"),
+    ("U¡", "ট্ব"),
+    ("Æ", "ট্ট"),
+    ("Å", "ঞ্ঝ"),
+    ("Ä", "ঞ্জ"),
+    ("Ã", "ঞ্ছ"),
+    ("Â", "ঞ্চ"),
+    ("Rª", "জ্র"),
+    ("R¡", "জ্ব"),
+    ("Á", "জ্ঞ"),
+    ("À", "জ্ঝ"),
+    ("¾", "জ্জ"),
+    ("”¡", "চ্ব"),
+    ("”T", "চ্ঞ"),
+    ("”Q", "চ্ছ"),
+    ("”P", "চ্চ"),
+    ("•g", "ঙ্ম"),
+    ("•N", "ঙ্ঘ"),
+    ("½", "ঙ্গ"),
+    ("•L", "ঙ্খ"),
+    ("¼", "ঙ্ক"),
+    ("Nª", "ঘ্র"),
+    ("Nœ", "ঘ্ন"),
+    ("MÖ", "গ্র"),
+    ("M¥", "গ্ম"),
+    ("M¦", "গ্ব"),
+    ("Mœ", "গ্ন"),
+    ("M−", "গ্ল"),
+    ("»", "গ্ধ"),
+    ("Lª", "খ্র"),
+    ("·", "ক্স"),
+    ("¶", "ক্ষ"),
+    ("K¬", "ক্ল"),
+    ("Kè", "ক্ন"),
+    ("µ", "ক্র"),
+    ("´", "ক্ম"),
+    ("K¡", "ক্ব"),
+    ("³", "ক্ত"),
+    ("±", "ক্ট"),
+    ("°", "ক্ক"),
+    ("ÿ", "ড়্গ"),
+    ("¯‘", "স্তু"),
+    ("š‘", "ন্তু"),
+    ("¸", "গু"),
+    ("ï", "শু"),
+    ("û", "হু"),
+    ("ü", "হৃ"),
+    ("v", "া"),
+    ("x", "ী"),
+    ("y", "ু"),
+    ("z", "ু"),
+    ("“", "ু"),
+    ("–", "ু"),
+    ("~", "ূ"),
+    ("‚", "ূ"),
+    ("ƒ", "ূ"),
+    ("„", "ৃ"),
+    ("…", "ৃ"),
+    ("w", "ি"),
+    ("†", "ে"),
+    ("‡", "ে"),
+    ("ˆ", "ৈ"),
+    ("‰", "ৈ"),
+    ("Š", "ৗ"),
+    ("¨", "্য"),
+    ("©", "র্"),
+    ("&", "্"),
+    ("|", "।"),
+    ("\\", "॥"),
+];
+
+/// Kars which are written before their letters in the Bijoy encoding.
+const PRE_KARS: [&str; 3] = ["ি", "ে", "ৈ"];
+
+/// The reph, which is written after its letter in the Bijoy encoding.
+const REPH: &str = "র্";
+
+pub(crate) struct Bijoy;
+
+impl Converter for Bijoy {
+    fn encode(&self, text: &str) -> Conversion {
+        let text = normalize(text, Normalization::Bangla);
+        let mut output = String::with_capacity(text.len());
+        let mut start = 0;
+
+        // The encoder writes the `্তু` by replacing the last glyph of the
+        // conjunct with the `‘`, which is only right for the `ন্ত` and `স্ত`.
+        // The others are encoded without their u-kar, which is put after them.
+        for (index, _) in text.match_indices("্তু") {
+            let conjunct = &text[start..index + "্ত".len()];
+            let special = ["ন্ত", "স্ত"].into_iter().any(|special| {
+                conjunct
+                    .strip_suffix(special)
+                    .is_some_and(|rest| !rest.ends_with('্'))
+            });
+
+            if !special {
+                output.push_str(&unicode_to_bijoy(conjunct));
+                output.push('z');
+                start = index + "্তু".len();
+            }
+        }
+
+        output.push_str(&unicode_to_bijoy(&text[start..]));
+
+        Conversion {
+            text: output,
+            unmapped: unmapped(&text),
+        }
+    }
+
+    fn decode(&self, text: &str) -> Conversion {
+        static TABLE: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        let table = TABLE.get_or_init(|| GLYPHS.into_iter().collect());
+
+        let mut output = String::with_capacity(text.len() * 2);
+        let mut unmapped = Vec::new();
+        // Pre-base kar waiting for its letter.
+        let mut kar = None;
+        let mut tokens = substitute(text, table, &mut unmapped)
+            .into_iter()
+            .peekable();
+
+        while let Some(token) = tokens.next() {
+            if PRE_KARS.contains(&token) {
+                output.extend(kar.replace(token));
+                continue;
+            }
+
+            if !is_letter(token) {
+                output.extend(kar.take());
+                output.push_str(token);
+
+                // The hasanta is explicit when it's followed by a letter.
+                if token == "্" && tokens.peek().is_some_and(|token| is_letter(token)) {
+                    output.push('\u{200C}');
+                }
+                continue;
+            }
+
+            // The letter along with its ya-phala and reph.
+            let mut cluster = token.to_owned();
+            let mut reph = false;
+
+            while let Some(&next) = tokens.peek() {
+                match next {
+                    // র with the ya-phala is র‍্য, the র্য is written with the reph.
+                    "্য" if cluster == "র" => cluster.push_str("\u{200D}্য"),
+                    "্য" => cluster.push_str(next),
+                    REPH => reph = true,
+                    _ => break,
+                }
+                tokens.next();
+            }
+
+            if reph {
+                output.push_str(REPH);
+            }
+            output.push_str(&cluster);
+            output.extend(kar.take());
+        }
+
+        output.extend(kar);
+
+        Conversion {
+            text: normalize(&output, Normalization::Bangla),
+            unmapped,
+        }
+    }
+}
+
+/// Finds the characters and the conjuncts of the Unicode `text` which have no
+/// glyphs in the Bijoy encoding.
+fn unmapped(text: &str) -> Vec<String> {
+    static MAPPED: OnceLock<HashSet<&str>> = OnceLock::new();
+    let mapped = MAPPED.get_or_init(|| {
+        GLYPHS
+            .into_iter()
+            .map(|(_, unicode)| unicode)
+            // Written with the glyphs of their two parts.
+            .chain(["ো", "ৌ"])
+            .collect()
+    });
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut unmapped = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if !is_consonant(chars[index]) {
+            let c = chars[index];
+            let mut buffer = [0; 4];
+
+            if !c.is_ascii()
+                && !matches!(c, '\u{200C}' | '\u{200D}')
+                && !mapped.contains(&*c.encode_utf8(&mut buffer))
+            {
+                push_checked(&mut unmapped, c.to_string());
+            }
+
+            index += 1;
+            continue;
+        }
+
+        let mut end = index + 1;
+        while chars.get(end) == Some(&'্') && chars.get(end + 1).copied().is_some_and(is_consonant)
+        {
+            end += 2;
+        }
+
+        // The reph and the ya-phala have their own glyphs.
+        let conjunct: String = chars[index..end].iter().collect();
+        let conjunct = conjunct
+            .strip_prefix("র্")
+            .filter(|rest| !rest.is_empty())
+            .unwrap_or(&conjunct);
+        let conjunct = conjunct.strip_suffix("্য").unwrap_or(conjunct);
+
+        if !mapped.contains(conjunct) {
+            push_checked(&mut unmapped, conjunct.to_owned());
+        }
+
+        index = end;
+    }
+
+    unmapped
+}
+
+/// Checks if `c` is a consonant.
+fn is_consonant(c: char) -> bool {
+    matches!(c, 'ক'..='হ' | '\u{09DC}'..='\u{09DF}')
+}
+
+/// Checks if the `text` starts with a vowel or a consonant.
+fn is_letter(text: &str) -> bool {
+    text.starts_with(|c| matches!(c, 'অ'..='ঔ') || is_consonant(c))
+}
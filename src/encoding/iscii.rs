@@ -0,0 +1,137 @@
+//! ISCII encoding (IS 13194:1991).
+//!
+//! The bytes of the encoding are represented by the Latin-1 characters of the
+//! same code points, like the Bijoy encoding. They are given back by
+//! [`Conversion::to_bytes()`]. The nukta letters and `ৎ` are
+//! written with the nukta, which makes the hasanta before it a soft hasanta.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::{substitute, Conversion, Converter};
+use crate::normalization::{normalize, Normalization};
+
+/// Characters of the ISCII encoding and their Unicode text.
+#[rustfmt::skip]
+const CHARACTERS: [(&str, &str); 83] = [
+    ("\u{A1}", "ঁ"),
+    ("\u{A2}", "ং"),
+    ("\u{A3}", "ঃ"),
+    ("\u{A4}", "অ"),
+    ("\u{A5}", "আ"),
+    ("\u{A6}", "ই"),
+    ("\u{A7}", "ঈ"),
+    ("\u{A8}", "উ"),
+    ("\u{A9}", "ঊ"),
+    ("\u{AA}", "ঋ"),
+    ("\u{AC}", "এ"),
+    ("\u{AD}", "ঐ"),
+    ("\u{B0}", "ও"),
+    ("\u{B1}", "ঔ"),
+    ("\u{B3}", "ক"),
+    ("\u{B4}", "খ"),
+    ("\u{B5}", "গ"),
+    ("\u{B6}", "ঘ"),
+    ("\u{B7}", "ঙ"),
+    ("\u{B8}", "চ"),
+    ("\u{B9}", "ছ"),
+    ("\u{BA}", "জ"),
+    ("\u{BB}", "ঝ"),
+    ("\u{BC}", "ঞ"),
+    ("\u{BD}", "ট"),
+    ("\u{BE}", "ঠ"),
+    ("\u{BF}", "ড"),
+    ("\u{C0}", "ঢ"),
+    ("\u{C1}", "ণ"),
+    ("\u{C2}", "ত"),
+    ("\u{C3}", "থ"),
+    ("\u{C4}", "দ"),
+    ("\u{C5}", "ধ"),
+    ("\u{C6}", "ন"),
+    ("\u{C8}", "প"),
+    ("\u{C9}", "ফ"),
+    ("\u{CA}", "ব"),
+    ("\u{CB}", "ভ"),
+    ("\u{CC}", "ম"),
+    ("\u{CD}", "য"),
+    ("\u{CF}", "র"),
+    ("\u{D1}", "ল"),
+    ("\u{D5}", "শ"),
+    ("\u{D6}", "ষ"),
+    ("\u{D7}", "স"),
+    ("\u{D8}", "হ"),
+    ("\u{DA}", "া"),
+    ("\u{DB}", "ি"),
+    ("\u{DC}", "ী"),
+    ("\u{DD}", "ু"),
+    ("\u{DE}", "ূ"),
+    ("\u{DF}", "ৃ"),
+    ("\u{E1}", "ে"),
+    ("\u{E2}", "ৈ"),
+    ("\u{E5}", "ো"),
+    ("\u{E6}", "ৌ"),
+    ("\u{E8}", "্"),
+    ("\u{E9}", "়"),
+    ("\u{EA}", "।"),
+    ("\u{F1}", "০"),
+    ("\u{F2}", "১"),
+    ("\u{F3}", "২"),
+    ("\u{F4}", "৩"),
+    ("\u{F5}", "৪"),
+    ("\u{F6}", "৫"),
+    ("\u{F7}", "৬"),
+    ("\u{F8}", "৭"),
+    ("\u{F9}", "৮"),
+    ("\u{FA}", "৯"),
+    ("\u{BF}\u{E9}", "\u{09DC}"),
+    ("\u{C0}\u{E9}", "\u{09DD}"),
+    ("\u{CD}\u{E9}", "\u{09DF}"),
+    ("\u{C2}\u{E8}\u{E9}", "ৎ"),
+    ("\u{E8}\u{E9}", "্\u{200D}"),
+    ("\u{E8}\u{E8}", "্\u{200C}"),
+    ("\u{EA}\u{EA}", "॥"),
+    ("\u{EA}\u{E9}", "ঽ"),
+    ("\u{A6}\u{E9}", "ঌ"),
+    ("\u{A7}\u{E9}", "ৡ"),
+    ("\u{AA}\u{E9}", "ৠ"),
+    ("\u{DB}\u{E9}", "ৢ"),
+    ("\u{DC}\u{E9}", "ৣ"),
+    ("\u{DF}\u{E9}", "ৄ"),
+];
+
+pub(crate) struct Iscii;
+
+impl Converter for Iscii {
+    fn encode(&self, text: &str) -> Conversion {
+        static TABLE: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        let table = TABLE.get_or_init(|| {
+            CHARACTERS
+                .into_iter()
+                .map(|(iscii, unicode)| (unicode, iscii))
+                .collect()
+        });
+
+        let mut unmapped = Vec::new();
+        let text = substitute(
+            &normalize(text, Normalization::Bangla),
+            table,
+            &mut unmapped,
+        )
+        .concat();
+
+        Conversion { text, unmapped }
+    }
+
+    fn decode(&self, text: &str) -> Conversion {
+        static TABLE: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        let table = TABLE.get_or_init(|| CHARACTERS.into_iter().collect());
+
+        let mut unmapped = Vec::new();
+        let text = substitute(text, table, &mut unmapped).concat();
+
+        Conversion {
+            text: normalize(&text, Normalization::Bangla),
+            unmapped,
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! Legacy ANSI encodings of the Bengali text.
+//!
+//! Before Unicode, the Bengali text was written in 8-bit encodings which reuse
+//! the code points of the Latin-1 and Windows-1252 code pages. Some
+//! applications still expect the text in such an encoding.
+//!
+//! Every encoding has a [`Converter`], which is looked up by its [`Encoding`].
+//! The text is converted in both directions by [`Encoding::encode()`] and
+//! [`Encoding::decode()`], which also report the pieces of the text that
+//! couldn't be mapped.
+
+mod bijoy;
+mod iscii;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utility::push_checked;
+
+/// Length of the longest piece of the conversion tables, in characters.
+const LONGEST: usize = 3;
+
+/// Characters of the bytes `0x80` to `0x9F` in the Windows-1252 code page,
+/// the undefined ones are the C1 control characters.
+#[rustfmt::skip]
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// Converter between the Unicode and a legacy encoding.
+pub trait Converter: Send + Sync {
+    /// Converts the Unicode `text` into the encoding.
+    fn encode(&self, text: &str) -> Conversion;
+
+    /// Converts the `text` of the encoding into the Unicode.
+    fn decode(&self, text: &str) -> Conversion;
+}
+
+/// Result of a conversion.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Conversion {
+    /// The converted text.
+    pub text: String,
+    /// Pieces of the text which couldn't be mapped, like a character or a
+    /// conjunct missing from the encoding, in the order they first appear.
+    ///
+    /// Depending on the encoding, they are dropped from the converted text or
+    /// kept as they are.
+    pub unmapped: Vec<String>,
+}
+
+impl Conversion {
+    /// Get the bytes of the text converted into a legacy encoding.
+    ///
+    /// The text of a legacy encoding is made of the characters of its code
+    /// points in the Windows-1252 code page, which are mapped back here.
+    ///
+    /// Returns `None` if an unmapped character is kept in the text, as its
+    /// code point would be drawn as another glyph, like `é` as `ল্ক` in Bijoy.
+    /// The same goes for a character which isn't in the code page, like the
+    /// `−` glyph of Bijoy, which is only available by its character.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        if self
+            .unmapped
+            .iter()
+            .any(|piece| self.text.contains(piece.as_str()))
+        {
+            return None;
+        }
+
+        self.text
+            .chars()
+            .map(|c| match c {
+                '\0'..='\u{7F}' | '\u{A0}'..='\u{FF}' => Some(c as u8),
+                _ => WINDOWS_1252
+                    .iter()
+                    .position(|&w| w == c)
+                    .map(|index| 0x80 + index as u8),
+            })
+            .collect()
+    }
+}
+
+/// Encoding of the suggestions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Encoding {
+    /// The suggestions are kept in the Unicode.
+    #[default]
+    Unicode,
+    /// Bijoy 2000 encoding.
+    Bijoy,
+    /// ISCII encoding (IS 13194:1991).
+    Iscii,
+}
+
+impl Encoding {
+    /// Get the converter of the encoding, `None` for the Unicode.
+    pub fn converter(self) -> Option<&'static dyn Converter> {
+        match self {
+            Encoding::Unicode => None,
+            Encoding::Bijoy => Some(&bijoy::Bijoy),
+            Encoding::Iscii => Some(&iscii::Iscii),
+        }
+    }
+
+    /// Converts the Unicode `text` into the encoding.
+    pub fn encode(self, text: &str) -> Conversion {
+        match self.converter() {
+            Some(converter) => converter.encode(text),
+            None => Conversion {
+                text: text.to_owned(),
+                unmapped: Vec::new(),
+            },
+        }
+    }
+
+    /// Converts the `text` of the encoding into the Unicode.
+    pub fn decode(self, text: &str) -> Conversion {
+        match self.converter() {
+            Some(converter) => converter.decode(text),
+            None => Conversion {
+                text: text.to_owned(),
+                unmapped: Vec::new(),
+            },
+        }
+    }
+}
+
+/// Splits the `text` into the longest pieces found in the `table` and returns
+/// their replacements. The characters which aren't found are kept as they are,
+/// the non-ASCII ones among them are put into the `unmapped`.
+fn substitute<'a>(
+    text: &'a str,
+    table: &HashMap<&str, &'a str>,
+    unmapped: &mut Vec<String>,
+) -> Vec<&'a str> {
+    let bounds: Vec<usize> = text
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain([text.len()])
+        .collect();
+    let mut pieces = Vec::with_capacity(bounds.len());
+    let mut start = 0;
+
+    while start + 1 < bounds.len() {
+        let longest = LONGEST.min(bounds.len() - 1 - start);
+        let found = (1..=longest).rev().find_map(|length| {
+            table
+                .get(&text[bounds[start]..bounds[start + length]])
+                .map(|&piece| (length, piece))
+        });
+
+        match found {
+            Some((length, piece)) => {
+                pieces.push(piece);
+                start += length;
+            }
+            None => {
+                let piece = &text[bounds[start]..bounds[start + 1]];
+                if !piece.is_ascii() {
+                    push_checked(unmapped, piece.to_owned());
+                }
+                pieces.push(piece);
+                start += 1;
+            }
+        }
+    }
+
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+    use std::fs::read_to_string;
+
+    use super::{Conversion, Encoding};
+    use crate::normalization::{normalize, Normalization};
+
+    /// Words of the dictionary which can't be written in the Bijoy encoding.
+    const BIJOY_UNMAPPABLE: [&str; 43] = [
+        // The ZWNJ is dropped.
+        "আলহামদুলিল্লাহ্\u{200C}",
+        "আল্লাহ্\u{200C}",
+        "ইনশাআল্লাহ্\u{200C}",
+        "ইন্টার\u{200C}্যাকশন",
+        "ইন্টার\u{200C}্যাকশনস",
+        "ইন্টার\u{200C}্যাক্টিভ",
+        "ইন্টার\u{200C}্যাক্টিভিটি",
+        "উমরাহ্\u{200C}",
+        "উল্লাহ্\u{200C}",
+        "ঋক্\u{200C}",
+        "ঋক্\u{200C}বেদ",
+        "ওমরাহ্\u{200C}",
+        "খুদাহ্\u{200C}",
+        "বিসমিল্লাহ্\u{200C}",
+        "যাহ্\u{200C}",
+        "র\u{200C}্যাংগস",
+        "শাহ্\u{200C}",
+        // Conjuncts without a glyph.
+        "আক্দ",
+        "গোপ্ত্রী",
+        "জানুচঙ্ক্রমণ",
+        "জেরপ্থ্যালমিয়া",
+        "পোল্ট্রি",
+        "লঘ্বি",
+        "সঙ্ক্রম",
+        "সঙ্ক্রমণ",
+        "সঙ্ক্রমিত",
+        "সঙ্ক্রান্ত",
+        "সঙ্ক্রান্তি",
+        "সঙ্ক্রাম",
+        "সঙ্ক্রামক",
+        "সঙ্ক্রামিত",
+        "সঙ্ক্রামী",
+        "সঙ্ক্রুদ্ধ",
+        "সিম্প্লেক্স",
+        "স্ক্লেরোসিস",
+        // Misspelled words, with a repeated sign.
+        "ইন্টার্র্নি",
+        "প্র্রকাশকাল",
+        "বন্ধুত্ব্ব",
+        "সম্প্র্রচার",
+        "সম্প্র্রচারিত",
+        "স্ত্ততি",
+        "স্ত্ততিবাদ",
+        // `ণ` followed by `ঢ` is read back as their conjunct.
+        "প্রাণঢালা",
+    ];
+
+    /// Encodes the words of the dictionary and returns the ones which aren't
+    /// decoded back exactly, after the NFC, along with their decoded text.
+    fn round_trip(encoding: Encoding) -> BTreeMap<String, String> {
+        let dictionary =
+            read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/data/dictionary.json")).unwrap();
+        let dictionary: HashMap<String, Vec<String>> = serde_json::from_str(&dictionary).unwrap();
+
+        dictionary
+            .into_values()
+            .flatten()
+            .filter_map(|word| {
+                let decoded = encoding.decode(&encoding.encode(&word).text).text;
+                let decoded = normalize(&decoded, Normalization::Nfc);
+                let word = normalize(&word, Normalization::Nfc);
+                (decoded != word).then_some((word, decoded))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unicode() {
+        let conversion = Encoding::Unicode.encode("আমি");
+        assert_eq!(conversion.text, "আমি");
+        assert!(conversion.unmapped.is_empty());
+        assert_eq!(Encoding::Unicode.decode("আমি").text, "আমি");
+    }
+
+    #[test]
+    fn test_bijoy() {
+        let encode = |text| Encoding::Bijoy.encode(text).text;
+        let decode = |text| Encoding::Bijoy.decode(text).text;
+
+        assert_eq!(encode("বাংলা"), "evsjv");
+        assert_eq!(decode("evsjv"), "বাংলা");
+        assert_eq!(decode("wKsKZ©e¨weg~p"), "কিংকর্তব্যবিমূ\u{09DD}");
+        assert_eq!(decode("†KŠ"), "কৌ");
+        assert_eq!(decode("wKš‘"), "কিন্তু");
+        assert_eq!(decode("i¨ve"), "র\u{200D}্যাব");
+        assert_eq!(decode("¯c&j"), "স্প্\u{200C}ল");
+        assert_eq!(decode("ÒAvwgÓ|"), "“আমি”।");
+        assert_eq!(encode("শক্তি ভর্তুকি"), "kw³ fZ©zwK");
+        assert_eq!(decode("kw³ fZ©zwK"), "শক্তি ভর্তুকি");
+
+        // The Bijoy encoding has neither the ZWNJ nor the glyphs of a few
+        // conjuncts, like the `ঙ্ক্র`, so a handful of words are lost.
+        let lost: BTreeSet<String> = round_trip(Encoding::Bijoy).into_keys().collect();
+        let unmappable = BIJOY_UNMAPPABLE
+            .iter()
+            .map(|word| normalize(word, Normalization::Nfc))
+            .collect();
+        assert_eq!(lost, unmappable);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let bytes = |encoding: Encoding, text| encoding.encode(text).to_bytes();
+
+        assert_eq!(
+            bytes(Encoding::Bijoy, "“আমি”।").unwrap(),
+            [0xD2, b'A', b'v', b'w', b'g', 0xD3, b'|']
+        );
+        assert_eq!(bytes(Encoding::Bijoy, "কৌ").unwrap(), [0x86, b'K', 0x8A]);
+        assert_eq!(bytes(Encoding::Bijoy, "শ্ল"), None);
+        assert_eq!(bytes(Encoding::Bijoy, "লঘ্বি ও সঙ্ক্রম, é"), None);
+        // The dropped pieces don't matter.
+        assert_eq!(bytes(Encoding::Bijoy, "লঘ্বি").unwrap(), [b'j', b'w']);
+        assert_eq!(
+            bytes(Encoding::Iscii, "বাংলা").unwrap(),
+            [0xCA, 0xDA, 0xA2, 0xD1, 0xDA]
+        );
+        assert_eq!(bytes(Encoding::Iscii, "বাংলা ৺"), None);
+    }
+
+    #[test]
+    fn test_bijoy_unmapped() {
+        assert_eq!(
+            Encoding::Bijoy.encode("লঘ্বি ও সঙ্ক্রম, é"),
+            Conversion {
+                text: "jw I mg, é".to_owned(),
+                unmapped: vec!["ঘ্ব".to_owned(), "ঙ্ক্র".to_owned(), "é".to_owned()],
+            }
+        );
+        assert!(Encoding::Bijoy.encode("কর্মী বিদ্যুৎ").unmapped.is_empty());
+
+        let conversion = Encoding::Bijoy.decode("Avwg ™ আমি");
+        assert_eq!(conversion.text, "আমি ™ আমি");
+        assert_eq!(conversion.unmapped, ["™", "আ", "ম", "ি"]);
+    }
+
+    #[test]
+    fn test_iscii() {
+        let encode = |text| Encoding::Iscii.encode(text).text;
+        let decode = |text| Encoding::Iscii.decode(text).text;
+
+        assert_eq!(encode("বাংলা"), "\u{CA}\u{DA}\u{A2}\u{D1}\u{DA}");
+        assert_eq!(decode("\u{CA}\u{DA}\u{A2}\u{D1}\u{DA}"), "বাংলা");
+        assert_eq!(encode("উৎসব"), "\u{A8}\u{C2}\u{E8}\u{E9}\u{D7}\u{CA}");
+        assert_eq!(decode("\u{CA}\u{DA}\u{CD}\u{E9}\u{DD}"), "বা\u{09DF}ু");
+
+        assert_eq!(round_trip(Encoding::Iscii), BTreeMap::new());
+    }
+
+    #[test]
+    fn test_iscii_unmapped() {
+        let conversion = Encoding::Iscii.encode("বাংলা ৺");
+        assert_eq!(conversion.text, "\u{CA}\u{DA}\u{A2}\u{D1}\u{DA} ৺");
+        assert_eq!(conversion.unmapped, ["৺"]);
+
+        let conversion = Encoding::Iscii.decode("\u{CA}\u{D0}");
+        assert_eq!(conversion.text, "ব\u{D0}");
+        assert_eq!(conversion.unmapped, ["\u{D0}"]);
+    }
+}
@@ -0,0 +1,208 @@
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+use crate::config::Config;
+use crate::context::Method;
+use crate::data::Data;
+use crate::encoding::Encoding;
+use crate::keycodes::keycode_to_char;
+use crate::suggestion::{CandidateKind, Rank, Suggestion};
+
+/// Number of completions suggested if the candidates aren't paginated.
+const MAX_COMPLETIONS: usize = 8;
+
+/// Method of the English mode.
+///
+/// The typed text is passed through as it is. If an English word list is
+/// configured, the words which start with the typed text are suggested.
+pub(crate) struct EnglishMethod {
+    buffer: String,
+    // Lower cased words and the words, sorted by the former.
+    words: Vec<(String, String)>,
+    word_list: PathBuf,
+}
+
+impl EnglishMethod {
+    pub(crate) fn new(config: &Config) -> Self {
+        let mut method = EnglishMethod {
+            buffer: String::with_capacity(20),
+            words: Vec::new(),
+            word_list: PathBuf::new(),
+        };
+        method.load_word_list(config);
+        method
+    }
+
+    /// Loads the word list of the `config`, if it has been changed.
+    fn load_word_list(&mut self, config: &Config) {
+        let path = config.get_english_word_list();
+
+        if self.word_list == path {
+            return;
+        }
+
+        self.word_list = path.to_owned();
+        self.words = read_to_string(path)
+            .map(|list| {
+                list.lines()
+                    .map(str::trim)
+                    .filter(|word| !word.is_empty())
+                    .map(|word| (word.to_lowercase(), word.to_owned()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.words.sort_unstable();
+        self.words.dedup();
+    }
+
+    /// Returns the words which start with the typed text.
+    fn completions(&self, limit: usize) -> Vec<String> {
+        let prefix = self.buffer.to_lowercase();
+        let start = self.words.partition_point(|(word, _)| *word < prefix);
+        // Follow the case of the typed text, like `Hel` → `Hello`.
+        let capitalize = self.buffer.starts_with(char::is_uppercase);
+
+        self.words[start..]
+            .iter()
+            .take_while(|(word, _)| word.starts_with(&prefix))
+            .map(|(_, word)| {
+                if capitalize && word.starts_with(char::is_lowercase) {
+                    let mut chars = word.chars();
+                    chars.next().unwrap().to_uppercase().chain(chars).collect()
+                } else {
+                    word.clone()
+                }
+            })
+            .filter(|word| *word != self.buffer)
+            .take(limit)
+            .collect()
+    }
+
+    fn create_suggestion(&self, selection: usize, config: &Config) -> Suggestion {
+        let purpose = config.get_input_purpose();
+
+        if self.words.is_empty() || purpose.is_passthrough() || purpose.is_numeric() {
+            return Suggestion::new_lonely(self.buffer.clone(), Encoding::Unicode);
+        }
+
+        let limit = if config.get_page_size() != 0 {
+            usize::MAX
+        } else {
+            MAX_COMPLETIONS
+        };
+        let mut suggestions = vec![Rank::first_ranked(
+            self.buffer.clone(),
+            CandidateKind::Typed,
+        )];
+        suggestions.extend(
+            self.completions(limit)
+                .into_iter()
+                .map(|word| Rank::last_ranked(word, 1, CandidateKind::English)),
+        );
+
+        Suggestion::new(
+            self.buffer.clone(),
+            &suggestions,
+            selection,
+            Encoding::Unicode,
+        )
+    }
+}
+
+impl Method for EnglishMethod {
+    fn get_suggestion(
+        &mut self,
+        key: u16,
+        _modifier: u8,
+        selection: u8,
+        _data: &Data,
+        config: &Config,
+    ) -> Suggestion {
+        self.buffer.push(keycode_to_char(key));
+        self.create_suggestion(selection.into(), config)
+    }
+
+    fn candidate_committed(&mut self, _: usize, _: &Config) {
+        self.buffer.clear();
+    }
+
+    fn update_engine(&mut self, config: &Config) {
+        self.load_word_list(config);
+    }
+
+    fn ongoing_input_session(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    fn finish_input_session(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn backspace_event(&mut self, ctrl: bool, _: &Data, config: &Config) -> Suggestion {
+        if ctrl {
+            self.buffer.clear();
+        } else {
+            self.buffer.pop();
+        }
+
+        if self.buffer.is_empty() {
+            Suggestion::empty()
+        } else {
+            self.create_suggestion(0, config)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnglishMethod;
+    use crate::config::get_phonetic_method_defaults;
+    use crate::context::Method;
+    use crate::data::Data;
+    use crate::keycodes::{VC_E, VC_H, VC_H_SHIFT, VC_L};
+
+    #[test]
+    fn test_completions() {
+        let dir = std::env::temp_dir().join(format!("riti-english-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("words.txt");
+        std::fs::write(&path, "help\nhello\nHelsinki\nhealth\n\nworld\n").unwrap();
+
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        let mut method = EnglishMethod::new(&config);
+
+        // Without a word list
+        let suggestion = method.get_suggestion(VC_H, 0, 0, &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion(), Some("h"));
+        method.finish_input_session();
+
+        assert!(config.set_english_word_list(path.to_str().unwrap()));
+        method.update_engine(&config);
+        method.get_suggestion(VC_H, 0, 0, &data, &config);
+        method.get_suggestion(VC_E, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_L, 0, 0, &data, &config);
+        assert_eq!(
+            suggestion.get_suggestions().unwrap(),
+            ["hel", "hello", "help", "Helsinki"]
+        );
+
+        let suggestion = method.backspace_event(false, &data, &config);
+        assert_eq!(
+            suggestion.get_suggestions().unwrap(),
+            ["he", "health", "hello", "help", "Helsinki"]
+        );
+        method.finish_input_session();
+
+        method.get_suggestion(VC_H_SHIFT, 0, 0, &data, &config);
+        method.get_suggestion(VC_E, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_L, 0, 0, &data, &config);
+        assert_eq!(
+            suggestion.get_suggestions().unwrap(),
+            ["Hel", "Hello", "Help", "Helsinki"]
+        );
+        assert!(method.backspace_event(true, &data, &config).is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,9 +1,83 @@
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::null_mut;
 
 use crate::config::Config;
-use crate::context::RitiContext;
-use crate::suggestion::Suggestion;
+use crate::context::{InputMode, InputPurpose, RitiContext};
+use crate::encoding::{Conversion, Encoding};
+use crate::language::Language;
+use crate::normalization::Normalization;
+use crate::profile::Profile;
+use crate::romanization::romanize;
+use crate::suggestion::{CandidateKind, RankTier, Suggestion};
+
+/// Runs `f` and returns its result, or `default` if it panicked.
+///
+/// A panic must never unwind across the FFI boundary, so every FFI function
+/// body is run through this as a last resort. Invalid arguments (null
+/// pointers, strings which aren't UTF-8, etc.) are checked by the functions
+/// themselves, which return the same `default` value for them.
+fn guard<T>(default: T, f: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(default)
+}
+
+/// Borrows the string `ptr` received from C.
+///
+/// Returns `None` if the pointer is null or the string isn't UTF-8.
+///
+/// # Safety
+///
+/// A non-null `ptr` must point to a NUL-terminated string which outlives `'a`.
+unsafe fn to_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(ptr).to_str().ok()
+}
+
+/// Converts the raw `value` of an [`InputPurpose`] received from C.
+///
+/// An out of range value can't be taken as the enum itself without undefined
+/// behaviour, so it is rejected here.
+fn input_purpose(value: u32) -> Option<InputPurpose> {
+    use InputPurpose::*;
+
+    [Normal, Url, Email, Number, AsciiNumber, Password, Search]
+        .into_iter()
+        .find(|&purpose| purpose as u32 == value)
+}
+
+/// Converts the raw `value` of an [`InputMode`] received from C.
+fn input_mode(value: u32) -> Option<InputMode> {
+    [InputMode::Bengali, InputMode::English]
+        .into_iter()
+        .find(|&mode| mode as u32 == value)
+}
+
+/// Converts the raw `value` of a [`Normalization`] received from C.
+fn normalization(value: u32) -> Option<Normalization> {
+    use Normalization::*;
+
+    [None, Nfc, Bangla]
+        .into_iter()
+        .find(|&form| form as u32 == value)
+}
+
+/// Converts the raw `value` of an [`Encoding`] received from C.
+fn encoding(value: u32) -> Option<Encoding> {
+    [Encoding::Unicode, Encoding::Bijoy, Encoding::Iscii]
+        .into_iter()
+        .find(|&encoding| encoding as u32 == value)
+}
+
+/// Converts the raw `value` of a [`Language`] received from C.
+fn language(value: u32) -> Option<Language> {
+    [Language::Bengali, Language::Assamese]
+        .into_iter()
+        .find(|&language| language as u32 == value)
+}
 
 fn riti_free<T>(ptr: *mut T) {
     if !ptr.is_null() {
@@ -14,22 +88,26 @@ fn riti_free<T>(ptr: *mut T) {
 }
 
 // FFI functions for handling the `RitiContext` structure.
+//
+// None of the FFI functions panic. If a function is called with invalid
+// arguments, such as a null pointer, it returns a default value instead.
 
 /// Creates a new instance of RitiContext with a Config which is properly
 /// populated using `riti_config_set_*` set of functions.
 #[no_mangle]
 pub extern "C" fn riti_context_new_with_config(ptr: *const Config) -> *mut RitiContext {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(null_mut(), || {
+        let Some(config) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
 
-    Box::into_raw(Box::new(RitiContext::new_with_config(config)))
+        Box::into_raw(Box::new(RitiContext::new_with_config(config)))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_context_free(ptr: *mut RitiContext) {
-    riti_free(ptr)
+    guard((), || riti_free(ptr))
 }
 
 /// Generates suggestion for `key` press.
@@ -44,14 +122,15 @@ pub extern "C" fn riti_get_suggestion_for_key(
     modifier: u8,
     selection: u8,
 ) -> *mut Suggestion {
-    let context = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(null_mut(), || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
 
-    let suggestion = context.get_suggestion_for_key(key, modifier, selection);
+        let suggestion = context.get_suggestion_for_key(key, modifier, selection);
 
-    Box::into_raw(Box::new(suggestion))
+        Box::into_raw(Box::new(suggestion))
+    })
 }
 
 /// A candidate of the suggestion list was committed.
@@ -61,51 +140,54 @@ pub extern "C" fn riti_get_suggestion_for_key(
 /// This function will end the ongoing input session.
 #[no_mangle]
 pub extern "C" fn riti_context_candidate_committed(ptr: *mut RitiContext, index: usize) {
-    let context = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return;
+        };
 
-    context.candidate_committed(index)
+        context.candidate_committed(index)
+    })
 }
 
 /// Update the suggestion making engine. This would also look for changes
 /// in layout selection and AutoCorrect database.
 #[no_mangle]
 pub extern "C" fn riti_context_update_engine(ptr: *mut RitiContext, config: *const Config) {
-    let context = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    let config = unsafe {
-        assert!(!config.is_null());
-        &*config
-    };
+        let Some(config) = (unsafe { config.as_ref() }) else {
+            return;
+        };
 
-    context.update_engine(config)
+        context.update_engine(config)
+    })
 }
 
 /// Checks if there is an ongoing input session.
 #[no_mangle]
 pub extern "C" fn riti_context_ongoing_input_session(ptr: *mut RitiContext) -> bool {
-    let context = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(false, || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return false;
+        };
 
-    context.ongoing_input_session()
+        context.ongoing_input_session()
+    })
 }
 
 /// Finish the ongoing input session if any.
 #[no_mangle]
 pub extern "C" fn riti_context_finish_input_session(ptr: *mut RitiContext) {
-    let context = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return;
+        };
 
-    context.finish_input_session()
+        context.finish_input_session()
+    })
 }
 
 /// A BackSpace event.
@@ -122,63 +204,383 @@ pub extern "C" fn riti_context_backspace_event(
     ptr: *mut RitiContext,
     ctrl: bool,
 ) -> *mut Suggestion {
-    let context = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(null_mut(), || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        let suggestion = context.backspace_event(ctrl);
+
+        Box::into_raw(Box::new(suggestion))
+    })
+}
+
+/// Show the next page of the candidates.
+///
+/// Returns a null pointer if the candidates aren't paginated.
+#[no_mangle]
+pub extern "C" fn riti_context_page_down(ptr: *mut RitiContext) -> *mut Suggestion {
+    guard(null_mut(), || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match context.page_down() {
+            Some(suggestion) => Box::into_raw(Box::new(suggestion)),
+            None => null_mut(),
+        }
+    })
+}
+
+/// Show the previous page of the candidates.
+///
+/// Returns a null pointer if the candidates aren't paginated.
+#[no_mangle]
+pub extern "C" fn riti_context_page_up(ptr: *mut RitiContext) -> *mut Suggestion {
+    guard(null_mut(), || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match context.page_up() {
+            Some(suggestion) => Box::into_raw(Box::new(suggestion)),
+            None => null_mut(),
+        }
+    })
+}
+
+/// Enable or disable the private input mode.
+///
+/// In private mode, nothing is learned from the user's input. It is
+/// intended to be toggled when the focus moves to a password field or
+/// a private chat.
+#[no_mangle]
+pub extern "C" fn riti_context_set_private_mode(ptr: *mut RitiContext, enabled: bool) {
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        context.set_private_mode(enabled)
+    })
+}
 
-    let suggestion = context.backspace_event(ctrl);
+/// Checks if the private input mode is enabled.
+#[no_mangle]
+pub extern "C" fn riti_context_is_private_mode(ptr: *mut RitiContext) -> bool {
+    guard(false, || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return false;
+        };
+
+        context.is_private_mode()
+    })
+}
+
+/// Switch to the input `mode`, a value of `InputMode`. Unknown values are ignored.
+///
+/// The ongoing input session of the previous mode is finished.
+#[no_mangle]
+pub extern "C" fn riti_context_set_input_mode(ptr: *mut RitiContext, mode: u32) {
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        if let Some(mode) = input_mode(mode) {
+            context.set_input_mode(mode)
+        }
+    })
+}
 
-    Box::into_raw(Box::new(suggestion))
+/// Get the input mode.
+#[no_mangle]
+pub extern "C" fn riti_context_get_input_mode(ptr: *mut RitiContext) -> InputMode {
+    guard(InputMode::Bengali, || {
+        let Some(context) = (unsafe { ptr.as_ref() }) else {
+            return InputMode::Bengali;
+        };
+
+        context.get_input_mode()
+    })
+}
+
+/// Set the purpose of the focused text field.
+///
+/// The engine adjusts its behaviour according to the `purpose`, a value of
+/// `InputPurpose`. Unknown values are ignored.
+#[no_mangle]
+pub extern "C" fn riti_context_set_input_purpose(ptr: *mut RitiContext, purpose: u32) {
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        if let Some(purpose) = input_purpose(purpose) {
+            context.set_input_purpose(purpose)
+        }
+    })
+}
+
+/// Switch to the configuration profile of the application `app_id`.
+///
+/// If the application has no profile, the config given to the context is used as it is.
+#[no_mangle]
+pub extern "C" fn riti_context_set_active_profile(ptr: *mut RitiContext, app_id: *const c_char) {
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        let Some(app_id) = (unsafe { to_str(app_id) }) else {
+            return;
+        };
+
+        context.set_active_profile(app_id)
+    })
+}
+
+/// Set the configuration profile of the application `app_id`.
+///
+/// `profile` is the JSON object of the overridden options, like
+/// `{"include_emoji": false, "encoding": "bijoy"}`. The options are named
+/// as in the settings file.
+///
+/// Returns `false` if the `profile` isn't valid.
+#[no_mangle]
+pub extern "C" fn riti_context_set_profile(
+    ptr: *mut RitiContext,
+    app_id: *const c_char,
+    profile: *const c_char,
+) -> bool {
+    guard(false, || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(app_id) = (unsafe { to_str(app_id) }) else {
+            return false;
+        };
+        let Some(profile) = (unsafe { to_str(profile) }) else {
+            return false;
+        };
+
+        match Profile::from_json(profile) {
+            Ok(profile) => {
+                context.set_profile(app_id, profile);
+                true
+            }
+            Err(_) => false,
+        }
+    })
+}
+
+/// Remove the configuration profile of the application `app_id`.
+#[no_mangle]
+pub extern "C" fn riti_context_remove_profile(ptr: *mut RitiContext, app_id: *const c_char) {
+    guard((), || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        let Some(app_id) = (unsafe { to_str(app_id) }) else {
+            return;
+        };
+
+        context.remove_profile(app_id)
+    })
+}
+
+/// Load the method of the layout `id` without activating it, so that
+/// switching to it is instant.
+///
+/// `id` is `avro_phonetic` or the path of a fixed method layout file.
+/// Returns `false` if it is neither.
+#[no_mangle]
+pub extern "C" fn riti_context_load_method(ptr: *mut RitiContext, id: *const c_char) -> bool {
+    guard(false, || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(id) = (unsafe { to_str(id) }) else {
+            return false;
+        };
+
+        context.load_method(id)
+    })
+}
+
+/// Switch to the loaded method of the layout `id`.
+///
+/// The previously active method is kept loaded along with its state.
+/// Returns `false` if the method of `id` isn't loaded.
+#[no_mangle]
+pub extern "C" fn riti_context_switch_method(ptr: *mut RitiContext, id: *const c_char) -> bool {
+    guard(false, || {
+        let Some(context) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(id) = (unsafe { to_str(id) }) else {
+            return false;
+        };
+
+        context.switch_method(id)
+    })
 }
 
 // FFI functions for handling the `Suggestion` structure.
 
 #[no_mangle]
 pub extern "C" fn riti_suggestion_free(ptr: *mut Suggestion) {
-    riti_free(ptr)
+    guard((), || riti_free(ptr))
 }
 
 /// Get the suggestion of the `index` from suggestions.
+///
+/// Returns a null pointer if the `Suggestion` is a *lonely* one or the `index` is out of range.
 #[no_mangle]
 pub extern "C" fn riti_suggestion_get_suggestion(
     ptr: *const Suggestion,
     index: usize,
 ) -> *mut c_char {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(null_mut(), || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match suggestion.get_candidates().and_then(|c| c.get(index)) {
+            Some(candidate) => unsafe {
+                CString::from_vec_unchecked(candidate.text.clone().into()).into_raw()
+            },
+            None => null_mut(),
+        }
+    })
+}
 
-    let string = suggestion.get_suggestions()[index].clone();
+/// Get the source of the suggestion of the `index` from suggestions.
+///
+/// Returns `Typed` if the `Suggestion` is a *lonely* one or the `index` is out of range.
+#[no_mangle]
+pub extern "C" fn riti_suggestion_get_candidate_kind(
+    ptr: *const Suggestion,
+    index: usize,
+) -> CandidateKind {
+    guard(CandidateKind::Typed, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return CandidateKind::Typed;
+        };
+
+        suggestion
+            .get_candidates()
+            .and_then(|c| c.get(index))
+            .map_or(CandidateKind::Typed, |c| c.kind)
+    })
+}
 
-    unsafe { CString::from_vec_unchecked(string.into()).into_raw() }
+/// Get the ranking group of the suggestion of the `index` from suggestions.
+///
+/// Returns `Other` if the `Suggestion` is a *lonely* one or the `index` is out of range.
+#[no_mangle]
+pub extern "C" fn riti_suggestion_get_candidate_tier(
+    ptr: *const Suggestion,
+    index: usize,
+) -> RankTier {
+    guard(RankTier::Other, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return RankTier::Other;
+        };
+
+        suggestion
+            .get_candidates()
+            .and_then(|c| c.get(index))
+            .map_or(RankTier::Other, |c| c.tier)
+    })
+}
+
+/// Get the score of the suggestion of the `index` from suggestions.
+///
+/// Returns `0` if the `Suggestion` is a *lonely* one or the `index` is out of range.
+#[no_mangle]
+pub extern "C" fn riti_suggestion_get_candidate_score(ptr: *const Suggestion, index: usize) -> u8 {
+    guard(0, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return 0;
+        };
+
+        suggestion
+            .get_candidates()
+            .and_then(|c| c.get(index))
+            .map_or(0, |c| c.score)
+    })
+}
+
+/// Get the annotation of the suggestion of the `index` from suggestions.
+///
+/// Returns a null pointer if the suggestion has no annotation, the `Suggestion`
+/// is a *lonely* one or the `index` is out of range.
+#[no_mangle]
+pub extern "C" fn riti_suggestion_get_candidate_annotation(
+    ptr: *const Suggestion,
+    index: usize,
+) -> *mut c_char {
+    guard(null_mut(), || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match suggestion
+            .get_candidates()
+            .and_then(|c| c.get(index))
+            .and_then(|c| c.annotation.as_ref())
+        {
+            Some(annotation) => unsafe {
+                CString::from_vec_unchecked(annotation.clone().into()).into_raw()
+            },
+            None => null_mut(),
+        }
+    })
 }
 
 /// Get the only suggestion of the *lonely* `Suggestion`.
+///
+/// Returns a null pointer if the `Suggestion` is not a *lonely* one.
 #[no_mangle]
 pub extern "C" fn riti_suggestion_get_lonely_suggestion(ptr: *const Suggestion) -> *mut c_char {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
-
-    unsafe { CString::from_vec_unchecked(suggestion.get_lonely_suggestion().into()).into_raw() }
+    guard(null_mut(), || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match suggestion.get_lonely_suggestion() {
+            Some(string) => unsafe { CString::from_vec_unchecked(string.into()).into_raw() },
+            None => null_mut(),
+        }
+    })
 }
 
+/// Get the auxiliary text.
+///
+/// Returns a null pointer if the `Suggestion` is a *lonely* one.
 #[no_mangle]
 pub extern "C" fn riti_suggestion_get_auxiliary_text(ptr: *const Suggestion) -> *mut c_char {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
-
-    unsafe { CString::from_vec_unchecked(suggestion.get_auxiliary_text().into()).into_raw() }
+    guard(null_mut(), || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match suggestion.get_auxiliary_text() {
+            Some(string) => unsafe { CString::from_vec_unchecked(string.into()).into_raw() },
+            None => null_mut(),
+        }
+    })
 }
 
 /// Get the pre-edit text from the list of the `index'.
 ///
 /// This returns the lone suggestion if the suggestion is a lonely one.
+/// Returns a null pointer if the `index` is out of range.
 ///
 /// The main purpose of the function is to convert the returning suggestion into
 /// the ANSI encoding if it was specified when the instance of this `Suggestion`
@@ -188,47 +590,96 @@ pub extern "C" fn riti_suggestion_get_pre_edit_text(
     ptr: *const Suggestion,
     index: usize,
 ) -> *mut c_char {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(null_mut(), || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match suggestion.get_pre_edit_text(index) {
+            Some(string) => unsafe { CString::from_vec_unchecked(string.into_bytes()).into_raw() },
+            None => null_mut(),
+        }
+    })
+}
 
-    unsafe {
-        CString::from_vec_unchecked(suggestion.get_pre_edit_text(index).into_bytes()).into_raw()
-    }
+/// Serializes the `Suggestion` into JSON.
+///
+/// The format is described by the JSON schema in `schema/suggestion.schema.json`.
+#[cfg(feature = "serde")]
+#[no_mangle]
+pub extern "C" fn riti_suggestion_to_json(ptr: *const Suggestion) -> *mut c_char {
+    guard(null_mut(), || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        unsafe { CString::from_vec_unchecked(suggestion.to_json().into_bytes()).into_raw() }
+    })
 }
 
 /// Free the allocated string.
 #[no_mangle]
 pub extern "C" fn riti_string_free(ptr: *mut c_char) {
-    if ptr.is_null() {
-        return;
-    }
+    guard((), || {
+        if ptr.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(CString::from_raw(ptr));
-    }
+        unsafe {
+            drop(CString::from_raw(ptr));
+        }
+    })
 }
 
 /// Returns index of the suggestion, which was previously selected.
+///
+/// Returns `0` if the `Suggestion` is a *lonely* one.
 #[no_mangle]
 pub extern "C" fn riti_suggestion_previously_selected_index(ptr: *const Suggestion) -> usize {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(0, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return 0;
+        };
 
-    suggestion.previously_selected_index()
+        suggestion.previously_selected_index().unwrap_or_default()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_suggestion_get_length(ptr: *const Suggestion) -> usize {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(0, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return 0;
+        };
 
-    suggestion.len()
+        suggestion.len()
+    })
+}
+
+/// Returns index of the current page of the suggestions.
+///
+/// Returns `0` if the `Suggestion` is a *lonely* one.
+#[no_mangle]
+pub extern "C" fn riti_suggestion_current_page(ptr: *const Suggestion) -> usize {
+    guard(0, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return 0;
+        };
+
+        suggestion.current_page().unwrap_or_default()
+    })
+}
+
+/// Get the number of suggestions in all of the pages.
+#[no_mangle]
+pub extern "C" fn riti_suggestion_get_total_length(ptr: *const Suggestion) -> usize {
+    guard(0, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return 0;
+        };
+
+        suggestion.total_len()
+    })
 }
 
 /// Returns `true` when the `Suggestion` struct is a **lonely** one, otherwise returns `false`.
@@ -236,22 +687,146 @@ pub extern "C" fn riti_suggestion_get_length(ptr: *const Suggestion) -> usize {
 /// A *lonely* `Suggestion` struct means that the struct has only one suggestion.
 #[no_mangle]
 pub extern "C" fn riti_suggestion_is_lonely(ptr: *const Suggestion) -> bool {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(false, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return false;
+        };
 
-    suggestion.is_lonely()
+        suggestion.is_lonely()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_suggestion_is_empty(ptr: *const Suggestion) -> bool {
-    let suggestion = unsafe {
-        assert!(!ptr.is_null());
-        &*ptr
-    };
+    guard(false, || {
+        let Some(suggestion) = (unsafe { ptr.as_ref() }) else {
+            return false;
+        };
 
-    suggestion.is_empty()
+        suggestion.is_empty()
+    })
+}
+
+// FFI functions for converting the text between the Unicode and the legacy
+// encodings.
+//
+// The `text` is a UTF-8 string. The text of a legacy encoding is made of the
+// characters of its code points in the Windows-1252 code page, its bytes are
+// given by `riti_conversion_get_bytes()`.
+
+/// Converts the Unicode `text` into the `encoding`.
+///
+/// The `encoding` is a value of `Encoding`. Null is returned for unknown values.
+#[no_mangle]
+pub extern "C" fn riti_conversion_encode(text: *const c_char, encoding: u32) -> *mut Conversion {
+    guard(null_mut(), || {
+        let Some(text) = (unsafe { to_str(text) }) else {
+            return null_mut();
+        };
+        let Some(encoding) = self::encoding(encoding) else {
+            return null_mut();
+        };
+
+        Box::into_raw(Box::new(encoding.encode(text)))
+    })
+}
+
+/// Converts the `text` of the `encoding` into the Unicode.
+///
+/// The `encoding` is a value of `Encoding`. Null is returned for unknown values.
+#[no_mangle]
+pub extern "C" fn riti_conversion_decode(text: *const c_char, encoding: u32) -> *mut Conversion {
+    guard(null_mut(), || {
+        let Some(text) = (unsafe { to_str(text) }) else {
+            return null_mut();
+        };
+        let Some(encoding) = self::encoding(encoding) else {
+            return null_mut();
+        };
+
+        Box::into_raw(Box::new(encoding.decode(text)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn riti_conversion_free(ptr: *mut Conversion) {
+    guard((), || riti_free(ptr))
+}
+
+/// Get the converted text.
+#[no_mangle]
+pub extern "C" fn riti_conversion_get_text(ptr: *const Conversion) -> *mut c_char {
+    guard(null_mut(), || {
+        let Some(conversion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        unsafe { CString::from_vec_unchecked(conversion.text.clone().into_bytes()).into_raw() }
+    })
+}
+
+/// Get the bytes of the text converted into a legacy encoding, as a
+/// NUL-terminated string.
+///
+/// Returns a null pointer if the text has a character which isn't in the
+/// Windows-1252 code page or an unmapped character kept as it is.
+#[no_mangle]
+pub extern "C" fn riti_conversion_get_bytes(ptr: *const Conversion) -> *mut c_char {
+    guard(null_mut(), || {
+        let Some(conversion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        // The bytes can't have a NUL in a C string.
+        match conversion.to_bytes().map(CString::new) {
+            Some(Ok(bytes)) => bytes.into_raw(),
+            _ => null_mut(),
+        }
+    })
+}
+
+/// Get the number of the pieces of the text which couldn't be mapped.
+#[no_mangle]
+pub extern "C" fn riti_conversion_get_unmapped_length(ptr: *const Conversion) -> usize {
+    guard(0, || {
+        let Some(conversion) = (unsafe { ptr.as_ref() }) else {
+            return 0;
+        };
+
+        conversion.unmapped.len()
+    })
+}
+
+/// Get the piece of the `index` among the ones which couldn't be mapped.
+///
+/// Returns a null pointer if the `index` is out of range.
+#[no_mangle]
+pub extern "C" fn riti_conversion_get_unmapped(
+    ptr: *const Conversion,
+    index: usize,
+) -> *mut c_char {
+    guard(null_mut(), || {
+        let Some(conversion) = (unsafe { ptr.as_ref() }) else {
+            return null_mut();
+        };
+
+        match conversion.unmapped.get(index) {
+            Some(piece) => unsafe { CString::from_vec_unchecked(piece.clone().into()).into_raw() },
+            None => null_mut(),
+        }
+    })
+}
+
+/// Romanises the Bengali `text` by ISO 15919.
+#[no_mangle]
+pub extern "C" fn riti_romanize(text: *const c_char) -> *mut c_char {
+    guard(null_mut(), || {
+        let Some(text) = (unsafe { to_str(text) }) else {
+            return null_mut();
+        };
+
+        unsafe { CString::from_vec_unchecked(romanize(text).into_bytes()).into_raw() }
+    })
 }
 
 /// Creates a new instance of Config which is used to initialize
@@ -262,13 +837,48 @@ pub extern "C" fn riti_suggestion_is_empty(ptr: *const Suggestion) -> bool {
 /// `riti_config_set_*` set of functions.
 #[no_mangle]
 pub extern "C" fn riti_config_new() -> *mut Config {
-    Box::into_raw(Box::default())
+    guard(null_mut(), || Box::into_raw(Box::default()))
+}
+
+/// Loads a Config from the settings file at `path`.
+///
+/// Returns a null pointer if the file couldn't be loaded.
+#[no_mangle]
+pub extern "C" fn riti_config_load(path: *const c_char) -> *mut Config {
+    guard(null_mut(), || {
+        let Some(path) = (unsafe { to_str(path) }) else {
+            return null_mut();
+        };
+
+        match Config::load(path) {
+            Ok(config) => Box::into_raw(Box::new(config)),
+            Err(_) => null_mut(),
+        }
+    })
+}
+
+/// Saves the Config into the settings file at `path`.
+///
+/// Returns `true` if the file was saved successfully.
+#[no_mangle]
+pub extern "C" fn riti_config_save(ptr: *const Config, path: *const c_char) -> bool {
+    guard(false, || {
+        let Some(config) = (unsafe { ptr.as_ref() }) else {
+            return false;
+        };
+
+        let Some(path) = (unsafe { to_str(path) }) else {
+            return false;
+        };
+
+        config.save(path).is_ok()
+    })
 }
 
 /// Free the allocated Config struct.
 #[no_mangle]
 pub extern "C" fn riti_config_free(ptr: *mut Config) {
-    riti_free(ptr)
+    guard((), || riti_free(ptr))
 }
 
 /// Sets the layout file path.
@@ -277,15 +887,17 @@ pub extern "C" fn riti_config_free(ptr: *mut Config) {
 /// Returns `true` if the layout file path or name is valid.
 #[no_mangle]
 pub extern "C" fn riti_config_set_layout_file(ptr: *mut Config, path: *const c_char) -> bool {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard(false, || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(layout) = (unsafe { to_str(path) }) else {
+            return false;
+        };
 
-    unsafe {
-        let layout = CStr::from_ptr(path).to_str().unwrap();
         config.set_layout_file_path(layout)
-    }
+    })
 }
 
 /// Sets the database directory path.
@@ -293,15 +905,17 @@ pub extern "C" fn riti_config_set_layout_file(ptr: *mut Config, path: *const c_c
 /// Returns `true` if the path exists.
 #[no_mangle]
 pub extern "C" fn riti_config_set_database_dir(ptr: *mut Config, path: *const c_char) -> bool {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard(false, || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(path) = (unsafe { to_str(path) }) else {
+            return false;
+        };
 
-    unsafe {
-        let path = CStr::from_ptr(path).to_str().unwrap();
         config.set_database_dir(path)
-    }
+    })
 }
 
 /// Sets the user specific writable directory path.
@@ -309,123 +923,710 @@ pub extern "C" fn riti_config_set_database_dir(ptr: *mut Config, path: *const c_
 /// Returns `true` if the path exists.
 #[no_mangle]
 pub extern "C" fn riti_config_set_user_dir(ptr: *mut Config, path: *const c_char) -> bool {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard(false, || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(path) = (unsafe { to_str(path) }) else {
+            return false;
+        };
 
-    unsafe {
-        let path = CStr::from_ptr(path).to_str().unwrap();
         config.set_user_dir(path)
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_suggestion_include_english(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_suggestion_include_english(option);
+    })
+}
 
-    config.set_suggestion_include_english(option);
+#[no_mangle]
+pub extern "C" fn riti_config_set_suggestion_include_emoji(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_suggestion_include_emoji(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_phonetic_suggestion(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_phonetic_suggestion(option);
+    })
+}
 
-    config.set_phonetic_suggestion(option);
+/// Set the case-insensitive phonetic input.
+#[no_mangle]
+pub extern "C" fn riti_config_set_phonetic_case_insensitive(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_phonetic_case_insensitive(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_suggestion(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_suggestion(option);
+        config.set_fixed_suggestion(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_auto_vowel(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_automatic_vowel(option);
+        config.set_fixed_automatic_vowel(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_auto_chandra(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_automatic_chandra(option);
+        config.set_fixed_automatic_chandra(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_traditional_kar(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_traditional_kar(option);
+        config.set_fixed_traditional_kar(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_old_reph(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_old_reph(option);
+        config.set_fixed_old_reph(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_numpad(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_numpad(option);
+        config.set_fixed_numpad(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_fixed_old_kar_order(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_fixed_old_kar_order(option);
+        config.set_fixed_old_kar_order(option);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_ansi_encoding(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_ansi_encoding(option);
+    })
+}
+
+/// Set the `encoding` of the output, a value of `Encoding`. Unknown values are ignored.
+#[no_mangle]
+pub extern "C" fn riti_config_set_encoding(ptr: *mut Config, encoding: u32) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        if let Some(encoding) = self::encoding(encoding) {
+            config.set_encoding(encoding);
+        }
+    })
+}
+
+/// Set the `language` of the input, a value of `Language`. Unknown values are ignored.
+#[no_mangle]
+pub extern "C" fn riti_config_set_language(ptr: *mut Config, language: u32) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        if let Some(language) = self::language(language) {
+            config.set_language(language);
+        }
+    })
+}
+
+/// Sets the number of candidates shown in a page, `0` disables pagination.
+#[no_mangle]
+pub extern "C" fn riti_config_set_page_size(ptr: *mut Config, size: usize) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_page_size(size);
+    })
+}
+
+/// Sets the key code which toggles the English mode, `0` disables the key.
+#[no_mangle]
+pub extern "C" fn riti_config_set_english_toggle_key(ptr: *mut Config, key: u16) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_english_toggle_key(key);
+    })
+}
 
-    config.set_ansi_encoding(option);
+/// Sets the path of the word list used for the completions of the English mode.
+///
+/// Returns `true` if the path exists.
+#[no_mangle]
+pub extern "C" fn riti_config_set_english_word_list(ptr: *mut Config, path: *const c_char) -> bool {
+    guard(false, || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return false;
+        };
+
+        let Some(path) = (unsafe { to_str(path) }) else {
+            return false;
+        };
+
+        config.set_english_word_list(path)
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn riti_config_set_smart_quote(ptr: *mut Config, option: bool) {
-    let config = unsafe {
-        assert!(!ptr.is_null());
-        &mut *ptr
-    };
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
 
-    config.set_smart_quote(option);
+        config.set_smart_quote(option);
+    })
+}
+
+/// Set the Bengali style quotation marks of the smart quote.
+#[no_mangle]
+pub extern "C" fn riti_config_set_smart_quote_bengali(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_smart_quote_bengali(option);
+    })
+}
+
+/// Set the suggestion of the typed numbers with the digit grouping.
+#[no_mangle]
+pub extern "C" fn riti_config_set_number_formatting(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_number_formatting(option);
+    })
+}
+
+/// Set the suggestion of the typed numbers spelled in words.
+#[no_mangle]
+pub extern "C" fn riti_config_set_number_words(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_number_words(option);
+    })
+}
+
+/// Set the normalisation `form` of the suggestions, a value of `Normalization`.
+/// Unknown values are ignored.
+#[no_mangle]
+pub extern "C" fn riti_config_set_normalization(ptr: *mut Config, form: u32) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        if let Some(form) = normalization(form) {
+            config.set_normalization(form);
+        }
+    })
+}
+
+/// Set the suggestion of the ISO 15919 romanisation of the best suggestion.
+#[no_mangle]
+pub extern "C" fn riti_config_set_romanization(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_romanization(option);
+    })
+}
+
+/// Set the smart punctuation of `...` as `…`.
+#[no_mangle]
+pub extern "C" fn riti_config_set_punctuation_ellipsis(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_punctuation_ellipsis(option);
+    })
+}
+
+/// Set the smart punctuation of `--` as `—`.
+#[no_mangle]
+pub extern "C" fn riti_config_set_punctuation_dash(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_punctuation_dash(option);
+    })
+}
+
+/// Set the smart punctuation of a sentence-final `.` as `।`.
+#[no_mangle]
+pub extern "C" fn riti_config_set_punctuation_dari(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_punctuation_dari(option);
+    })
+}
+
+/// Set the smart punctuation of `।।` as `॥`.
+#[no_mangle]
+pub extern "C" fn riti_config_set_punctuation_double_dari(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_punctuation_double_dari(option);
+    })
+}
+
+/// Set the smart spacing between a punctuation mark and the following Bengali word.
+#[no_mangle]
+pub extern "C" fn riti_config_set_punctuation_spacing(ptr: *mut Config, option: bool) {
+    guard((), || {
+        let Some(config) = (unsafe { ptr.as_mut() }) else {
+            return;
+        };
+
+        config.set_punctuation_spacing(option);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr::{null, null_mut};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::config::get_phonetic_method_defaults;
+    use crate::keycodes::{VC_A, VC_M};
+    use crate::store::MemoryStore;
+    use crate::suggestion::Rank;
+
+    fn take_string(ptr: *mut c_char) -> Option<String> {
+        if ptr.is_null() {
+            return None;
+        }
+        let string = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_owned();
+        riti_string_free(ptr);
+        Some(string)
+    }
+
+    #[test]
+    fn test_suggestion_ffi() {
+        let full = Box::into_raw(Box::new(Suggestion::new(
+            "ami".to_owned(),
+            &[
+                Rank::first_ranked("আমি".to_owned(), CandidateKind::AutoCorrect),
+                Rank::new_suggestion("আমই".to_owned(), "আমি"),
+            ],
+            1,
+            Encoding::Unicode,
+        )));
+        let lonely = Box::into_raw(Box::new(Suggestion::new_lonely(
+            "আমি".to_owned(),
+            Encoding::Unicode,
+        )));
+
+        // Full suggestion
+        assert_eq!(
+            take_string(riti_suggestion_get_suggestion(full, 1)).unwrap(),
+            "আমই"
+        );
+        assert_eq!(take_string(riti_suggestion_get_suggestion(full, 2)), None);
+        assert_eq!(
+            riti_suggestion_get_candidate_kind(full, 0),
+            CandidateKind::AutoCorrect
+        );
+        assert_eq!(riti_suggestion_get_candidate_tier(full, 0), RankTier::First);
+        assert_eq!(riti_suggestion_get_candidate_tier(full, 2), RankTier::Other);
+        assert_eq!(riti_suggestion_get_candidate_score(full, 1), 10);
+        assert_eq!(riti_suggestion_get_candidate_score(full, 2), 0);
+        assert_eq!(
+            take_string(riti_suggestion_get_candidate_annotation(full, 0)).unwrap(),
+            "Auto Correct"
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_candidate_annotation(full, 1)),
+            None
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_candidate_annotation(full, 2)),
+            None
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_lonely_suggestion(full)),
+            None
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_auxiliary_text(full)).unwrap(),
+            "ami"
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_pre_edit_text(full, 0)).unwrap(),
+            "আমি"
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_pre_edit_text(full, 9)),
+            None
+        );
+        assert_eq!(riti_suggestion_previously_selected_index(full), 1);
+        assert_eq!(riti_suggestion_get_length(full), 2);
+        assert_eq!(riti_suggestion_current_page(full), 0);
+        assert_eq!(riti_suggestion_get_total_length(full), 2);
+        assert!(!riti_suggestion_is_lonely(full));
+        assert!(!riti_suggestion_is_empty(full));
+
+        // Lonely suggestion
+        assert_eq!(take_string(riti_suggestion_get_suggestion(lonely, 0)), None);
+        assert_eq!(
+            riti_suggestion_get_candidate_kind(lonely, 0),
+            CandidateKind::Typed
+        );
+        assert_eq!(riti_suggestion_get_candidate_score(lonely, 0), 0);
+        assert_eq!(
+            take_string(riti_suggestion_get_candidate_annotation(lonely, 0)),
+            None
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_lonely_suggestion(lonely)).unwrap(),
+            "আমি"
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_auxiliary_text(lonely)),
+            None
+        );
+        assert_eq!(
+            take_string(riti_suggestion_get_pre_edit_text(lonely, 5)).unwrap(),
+            "আমি"
+        );
+        assert_eq!(riti_suggestion_previously_selected_index(lonely), 0);
+        assert_eq!(riti_suggestion_get_length(lonely), 1);
+        assert_eq!(riti_suggestion_current_page(lonely), 0);
+        assert_eq!(riti_suggestion_get_total_length(lonely), 1);
+        assert!(riti_suggestion_is_lonely(lonely));
+        assert!(!riti_suggestion_is_empty(lonely));
+
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                take_string(riti_suggestion_to_json(lonely)).unwrap(),
+                r#"{"type":"single","schema_version":1,"suggestion":"আমি","encoding":"unicode"}"#
+            );
+            assert!(riti_suggestion_to_json(null()).is_null());
+        }
+
+        riti_suggestion_free(full);
+        riti_suggestion_free(lonely);
+    }
+
+    #[test]
+    fn test_conversion_ffi() {
+        let text = CString::new("লঘ্বি ভাষা").unwrap();
+        let conversion = riti_conversion_encode(text.as_ptr(), Encoding::Bijoy as u32);
+        assert_eq!(
+            take_string(riti_conversion_get_text(conversion)).unwrap(),
+            "jw fvlv"
+        );
+        assert_eq!(riti_conversion_get_unmapped_length(conversion), 1);
+        assert_eq!(
+            take_string(riti_conversion_get_unmapped(conversion, 0)).unwrap(),
+            "ঘ্ব"
+        );
+        assert_eq!(
+            take_string(riti_conversion_get_unmapped(conversion, 1)),
+            None
+        );
+        riti_conversion_free(conversion);
+
+        let text = CString::new("fvlv").unwrap();
+        let conversion = riti_conversion_decode(text.as_ptr(), Encoding::Bijoy as u32);
+        assert_eq!(
+            take_string(riti_conversion_get_text(conversion)).unwrap(),
+            "ভাষা"
+        );
+        assert_eq!(riti_conversion_get_unmapped_length(conversion), 0);
+        riti_conversion_free(conversion);
+
+        let text = CString::new("বাংলা").unwrap();
+        let conversion = riti_conversion_encode(text.as_ptr(), Encoding::Iscii as u32);
+        let bytes = unsafe { CString::from_raw(riti_conversion_get_bytes(conversion)) };
+        assert_eq!(bytes.as_bytes(), [0xCA, 0xDA, 0xA2, 0xD1, 0xDA]);
+        riti_conversion_free(conversion);
+
+        assert!(riti_conversion_encode(null(), Encoding::Bijoy as u32).is_null());
+        assert!(riti_conversion_encode(text.as_ptr(), 100).is_null());
+        assert!(riti_conversion_decode(text.as_ptr(), 100).is_null());
+        assert!(riti_conversion_get_text(null()).is_null());
+        assert!(riti_conversion_get_bytes(null()).is_null());
+        assert_eq!(riti_conversion_get_unmapped_length(null()), 0);
+    }
+
+    #[test]
+    fn test_romanize_ffi() {
+        let text = CString::new("বাংলা").unwrap();
+        assert_eq!(take_string(riti_romanize(text.as_ptr())).unwrap(), "bāṁlā");
+        assert!(riti_romanize(null()).is_null());
+        let invalid = CString::new([0xE0, 0xA6]).unwrap();
+        assert!(riti_romanize(invalid.as_ptr()).is_null());
+    }
+
+    #[test]
+    fn test_null_pointers() {
+        let suggestion: *const Suggestion = null();
+        assert!(riti_suggestion_get_suggestion(suggestion, 0).is_null());
+        assert_eq!(
+            riti_suggestion_get_candidate_kind(suggestion, 0),
+            CandidateKind::Typed
+        );
+        assert_eq!(riti_suggestion_get_candidate_score(suggestion, 0), 0);
+        assert!(riti_suggestion_get_candidate_annotation(suggestion, 0).is_null());
+        assert!(riti_suggestion_get_lonely_suggestion(suggestion).is_null());
+        assert!(riti_suggestion_get_auxiliary_text(suggestion).is_null());
+        assert!(riti_suggestion_get_pre_edit_text(suggestion, 0).is_null());
+        assert_eq!(riti_suggestion_previously_selected_index(suggestion), 0);
+        assert_eq!(riti_suggestion_get_length(suggestion), 0);
+        assert_eq!(riti_suggestion_current_page(suggestion), 0);
+        assert_eq!(riti_suggestion_get_total_length(suggestion), 0);
+        assert!(!riti_suggestion_is_lonely(suggestion));
+        assert!(!riti_suggestion_is_empty(suggestion));
+        riti_suggestion_free(null_mut());
+        riti_string_free(null_mut());
+
+        let context: *mut RitiContext = null_mut();
+        assert!(riti_context_new_with_config(null()).is_null());
+        assert!(riti_get_suggestion_for_key(context, VC_A, 0, 0).is_null());
+        riti_context_candidate_committed(context, 0);
+        riti_context_update_engine(context, null());
+        assert!(!riti_context_ongoing_input_session(context));
+        riti_context_finish_input_session(context);
+        assert!(riti_context_backspace_event(context, false).is_null());
+        assert!(riti_context_page_down(context).is_null());
+        assert!(riti_context_page_up(context).is_null());
+        riti_context_set_private_mode(context, true);
+        assert!(!riti_context_is_private_mode(context));
+        riti_context_set_input_purpose(context, InputPurpose::Password as u32);
+        riti_context_set_active_profile(context, null());
+        assert!(!riti_context_set_profile(context, null(), null()));
+        riti_context_remove_profile(context, null());
+        riti_context_free(context);
+
+        let config: *mut Config = null_mut();
+        let path = CString::new("/nonexistent").unwrap();
+        assert!(riti_config_load(null()).is_null());
+        assert!(riti_config_load(path.as_ptr()).is_null());
+        assert!(!riti_config_save(config, path.as_ptr()));
+        assert!(!riti_config_set_layout_file(config, path.as_ptr()));
+        assert!(!riti_config_set_database_dir(config, path.as_ptr()));
+        assert!(!riti_config_set_user_dir(config, path.as_ptr()));
+        riti_config_set_suggestion_include_english(config, true);
+        riti_config_set_suggestion_include_emoji(config, true);
+        riti_config_set_phonetic_suggestion(config, true);
+        riti_config_set_fixed_suggestion(config, true);
+        riti_config_set_fixed_auto_vowel(config, true);
+        riti_config_set_fixed_auto_chandra(config, true);
+        riti_config_set_fixed_traditional_kar(config, true);
+        riti_config_set_fixed_old_reph(config, true);
+        riti_config_set_fixed_numpad(config, true);
+        riti_config_set_fixed_old_kar_order(config, true);
+        riti_config_set_ansi_encoding(config, true);
+        riti_config_set_encoding(config, Encoding::Iscii as u32);
+        riti_config_set_language(config, Language::Assamese as u32);
+        riti_config_set_number_words(config, false);
+        riti_config_set_romanization(config, true);
+        riti_config_set_smart_quote(config, true);
+        riti_config_set_page_size(config, 5);
+        riti_config_free(config);
+
+        let config = riti_config_new();
+        assert!(!riti_config_set_layout_file(config, null()));
+        riti_config_free(config);
+    }
+
+    #[test]
+    fn test_config_ffi() {
+        let config = riti_config_new();
+
+        riti_config_set_normalization(config, Normalization::Bangla as u32);
+        assert_eq!(
+            unsafe { &*config }.get_normalization(),
+            Normalization::Bangla
+        );
+        // Unknown values are ignored.
+        riti_config_set_normalization(config, 100);
+        assert_eq!(
+            unsafe { &*config }.get_normalization(),
+            Normalization::Bangla
+        );
+
+        riti_config_set_encoding(config, Encoding::Iscii as u32);
+        assert_eq!(unsafe { &*config }.get_encoding(), Encoding::Iscii);
+        riti_config_set_encoding(config, 100);
+        assert_eq!(unsafe { &*config }.get_encoding(), Encoding::Iscii);
+
+        riti_config_set_language(config, Language::Assamese as u32);
+        assert_eq!(unsafe { &*config }.get_language(), Language::Assamese);
+        riti_config_set_language(config, 100);
+        assert_eq!(unsafe { &*config }.get_language(), Language::Assamese);
+
+        riti_config_free(config);
+    }
+
+    #[test]
+    fn test_context_ffi() {
+        let mut config = get_phonetic_method_defaults();
+        config.set_user_store(Arc::new(MemoryStore::new()));
+        let config = Box::into_raw(Box::new(config));
+        let context = riti_context_new_with_config(config);
+        assert!(!context.is_null());
+
+        riti_suggestion_free(riti_get_suggestion_for_key(context, VC_A, 0, 0));
+        let suggestion = riti_get_suggestion_for_key(context, VC_M, 0, 0);
+        assert!(riti_context_ongoing_input_session(context));
+        assert!(!riti_suggestion_is_lonely(suggestion));
+        riti_suggestion_free(suggestion);
+
+        // Not paginated.
+        assert!(riti_context_page_down(context).is_null());
+
+        let probhat =
+            CString::new(concat!(env!("CARGO_MANIFEST_DIR"), "/data/Probhat.json")).unwrap();
+        assert!(!riti_context_switch_method(context, probhat.as_ptr()));
+        assert!(riti_context_load_method(context, probhat.as_ptr()));
+        assert!(riti_context_switch_method(context, probhat.as_ptr()));
+        assert!(!riti_context_ongoing_input_session(context));
+        assert!(!riti_context_load_method(context, null()));
+
+        riti_context_set_input_purpose(context, InputPurpose::Password as u32);
+        assert_eq!(
+            unsafe { &*context }.get_input_purpose(),
+            InputPurpose::Password
+        );
+        // Unknown values are ignored.
+        riti_context_set_input_purpose(context, 100);
+        assert_eq!(
+            unsafe { &*context }.get_input_purpose(),
+            InputPurpose::Password
+        );
+
+        riti_context_set_input_mode(context, InputMode::English as u32);
+        assert_eq!(riti_context_get_input_mode(context), InputMode::English);
+        riti_context_set_input_mode(context, 100);
+        assert_eq!(riti_context_get_input_mode(context), InputMode::English);
+        assert_eq!(riti_context_get_input_mode(null_mut()), InputMode::Bengali);
+
+        // Out of range candidate index.
+        riti_context_candidate_committed(context, 100);
+        assert!(!riti_context_ongoing_input_session(context));
+
+        let app_id = CString::new("editor").unwrap();
+        let profile = CString::new(r#"{"encoding": "bijoy", "page_size": 5}"#).unwrap();
+        let invalid = CString::new(r#"{"encoding": 1}"#).unwrap();
+        assert!(riti_context_set_profile(
+            context,
+            app_id.as_ptr(),
+            profile.as_ptr()
+        ));
+        assert!(!riti_context_set_profile(
+            context,
+            app_id.as_ptr(),
+            invalid.as_ptr()
+        ));
+        riti_context_set_active_profile(context, app_id.as_ptr());
+        assert_eq!(
+            unsafe { &*context }
+                .get_profile("editor")
+                .unwrap()
+                .page_size,
+            Some(5)
+        );
+        riti_context_remove_profile(context, app_id.as_ptr());
+        assert!(unsafe { &*context }.get_profile("editor").is_none());
+
+        riti_context_free(context);
+        riti_config_free(config);
+    }
 }
@@ -8,8 +8,9 @@ use crate::keycodes::*;
 use crate::utility::Modifiers;
 use LayoutModifiers::*;
 
+/// Modifier keys which select the character of a key in a layout.
 #[derive(Debug, PartialEq)]
-pub(crate) enum LayoutModifiers {
+pub enum LayoutModifiers {
     Normal,
     AltGr,
 }
@@ -18,12 +19,13 @@ pub(crate) enum LayoutModifiers {
 ///
 /// Provides the character for a specific input key combination.
 /// Initiated by parsing a fixed method layout file(JSON formatted)
-pub(crate) struct Layout {
+pub struct Layout {
     map: HashMap<String, String>,
 }
 
 impl Layout {
-    pub(crate) fn parse(json_key_map: Value) -> Option<Self> {
+    /// Parses the `layout` section of a fixed method layout file.
+    pub fn parse(json_key_map: Value) -> Option<Self> {
         serde_json::from_value(json_key_map)
             .map(|map| Layout { map })
             .ok()
@@ -43,7 +45,10 @@ impl Layout {
             .cloned()
     }
 
-    pub(crate) fn get_char_for_key(
+    /// Returns the character(s) which the `key` produces with the `modifier`.
+    ///
+    /// Keypad keys produce characters only if `fixed_numpad` is `true`.
+    pub fn get_char_for_key(
         &self,
         key: u16,
         modifier: LayoutModifiers,
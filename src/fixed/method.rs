@@ -2,9 +2,21 @@ use upodesh::bangla::suggest;
 
 use super::{chars::*, layout::Layout};
 use crate::config::Config;
-use crate::suggestion::{Rank, Suggestion};
-use crate::utility::{clean_string, get_modifiers, smart_quoter, SplittedString, Utility};
-use crate::{context::Method, data::Data, keycodes::keycode_to_char};
+use crate::suggestion::{CandidateKind, Rank, Suggestion};
+use crate::utility::{
+    clean_string, get_modifiers, push_checked, smart_quoter, Quotes, SplittedString, Utility,
+};
+use crate::{
+    context::{InputPurpose, Method},
+    data::Data,
+    encoding::Encoding,
+    keycodes::keycode_to_char,
+    language::Language,
+    normalization::{normalize, normalize_suggestions},
+    number::{self, Digits},
+    punctuation::{self, punctuate},
+    romanization::romanized_candidate,
+};
 
 const MARKS: &str = "`~!@#$%^+*-_=+\\|\"/;:,./?><()[]{}";
 
@@ -20,6 +32,8 @@ pub(crate) struct FixedMethod {
     pending_kar: Option<PendingKar>,
     suggestions: Vec<Rank>,
     layout: Layout,
+    // Open quotations of the committed text.
+    quotes: Quotes,
 }
 
 impl Method for FixedMethod {
@@ -32,6 +46,24 @@ impl Method for FixedMethod {
         config: &Config,
     ) -> Suggestion {
         let modifier = get_modifiers(modifier);
+        let purpose = config.get_input_purpose();
+
+        // Pass the typed text through if the text field doesn't want any conversion.
+        if purpose.is_passthrough() || purpose.is_numeric() {
+            let character = keycode_to_char(key);
+            self.typed.push(character);
+
+            if purpose == InputPurpose::Number {
+                self.buffer.push_str(&number::convert_digits(
+                    &character.to_string(),
+                    Digits::Bengali,
+                ));
+            } else {
+                self.buffer.push(character);
+            }
+
+            return self.create_suggestion(data, config);
+        }
 
         if let Some(value) =
             self.layout
@@ -49,7 +81,15 @@ impl Method for FixedMethod {
         self.create_suggestion(data, config)
     }
 
-    fn candidate_committed(&mut self, _index: usize, _: &Config) {
+    fn candidate_committed(&mut self, index: usize, config: &Config) {
+        // Pair the following standalone quotation marks with the committed text.
+        let purpose = config.get_input_purpose();
+        if config.get_fixed_suggestion() && !purpose.is_passthrough() && !purpose.is_numeric() {
+            if let Some(committed) = self.suggestions.get(index) {
+                self.quotes.update(committed.to_string());
+            }
+        }
+
         self.buffer.clear();
         self.typed.clear();
         self.pending_kar = None;
@@ -67,6 +107,8 @@ impl Method for FixedMethod {
         self.buffer.clear();
         self.typed.clear();
         self.pending_kar = None;
+        // The following text may not be written after the committed one.
+        self.quotes = Quotes::default();
     }
 
     fn backspace_event(&mut self, ctrl: bool, data: &Data, config: &Config) -> Suggestion {
@@ -114,14 +156,24 @@ impl FixedMethod {
             pending_kar: None,
             suggestions: Vec::with_capacity(10),
             layout,
+            quotes: Quotes::default(),
         }
     }
 
     fn create_suggestion(&mut self, data: &Data, config: &Config) -> Suggestion {
-        if config.get_fixed_suggestion() {
+        let purpose = config.get_input_purpose();
+
+        if purpose.is_passthrough() || purpose == InputPurpose::AsciiNumber {
+            Suggestion::new_lonely(self.buffer.clone(), Encoding::Unicode)
+        } else if purpose == InputPurpose::Number {
+            Suggestion::new_lonely(self.buffer.clone(), config.get_encoding())
+        } else if config.get_fixed_suggestion() {
             self.create_dictionary_suggestion(data, config)
         } else {
-            Suggestion::new_lonely(self.buffer.clone(), config.get_ansi_encoding())
+            Suggestion::new_lonely(
+                normalize(&self.buffer, config.get_normalization()),
+                config.get_encoding(),
+            )
         }
     }
 
@@ -130,7 +182,7 @@ impl FixedMethod {
 
         // Smart Quoting feature
         if config.get_smart_quote() {
-            string = smart_quoter(string);
+            string = smart_quoter(string, self.quotes, config.get_smart_quote_bengali());
         }
 
         let (first_part, word, last_part) = string.as_tuple();
@@ -138,10 +190,31 @@ impl FixedMethod {
         self.suggestions.clear();
 
         // Add the user's typed word.
-        self.suggestions.push(Rank::first_ranked(word.to_string()));
+        self.suggestions
+            .push(Rank::first_ranked(word.to_string(), CandidateKind::Typed));
 
         // Add suggestions from the dictionary while changing the Kar joinings if Traditional Kar Joining is set.
-        let mut words = suggest(&clean_string(word));
+        let mut words = match config.get_language() {
+            Language::Bengali => suggest(&clean_string(word)),
+            Language::Assamese => {
+                // Like the Bengali dictionary, the longer words are completed further.
+                let language = config.get_language();
+                let word = clean_string(word);
+                let extra = if word.chars().count() > 3 { 5 } else { 1 };
+                let words = data.complete_word(&word, extra);
+
+                if words.is_empty() {
+                    // The Assamese dictionary is small, so the words of the
+                    // Bengali dictionary are suggested in the Assamese spelling.
+                    suggest(&language.bengali_spelling(&word))
+                        .iter()
+                        .map(|word| language.phonetic_output(word))
+                        .collect()
+                } else {
+                    words
+                }
+            }
+        };
         words.sort_unstable();
         
         if config.get_fixed_traditional_kar() {
@@ -177,7 +250,7 @@ impl FixedMethod {
             }
         }
 
-        if !config.get_ansi_encoding() {
+        if config.get_suggestion_include_emoji() {
             // Emoji addition with Emoticons.
             if let Some(emoji) = data.get_emoji_by_emoticon(&self.typed) {
                 self.suggestions.push(Rank::emoji(emoji.to_owned()));
@@ -191,25 +264,66 @@ impl FixedMethod {
             }
         }
 
+        // The typed number with the digit grouping and in words.
+        let formatted = number::format(&self.buffer, Digits::Bengali)
+            .filter(|formatted| *formatted != self.buffer && config.get_number_formatting());
+        let spelled = number::spell(word)
+            .into_iter()
+            .filter(|_| config.get_number_words())
+            .map(|words| format!("{first_part}{words}{last_part}"));
+        self.suggestions.extend(
+            formatted
+                .into_iter()
+                .chain(spelled)
+                .zip(1..)
+                .map(|(text, rank)| Rank::last_ranked(text, rank, CandidateKind::Number)),
+        );
+
+        // Normalise the suggestions, which also removes the equivalent ones.
+        normalize_suggestions(&mut self.suggestions, config.get_normalization());
+
         // Sort the suggestions.
         self.suggestions.sort_unstable();
 
-        // Reduce the number of suggestions and add the typed english word at the end.
-        // Also check that the typed text is not already included (may happen
-        // when the control characters are typed).
-        if config.get_suggestion_include_english() && self.buffer != self.typed {
-            self.suggestions.truncate(8);
+        // The romanisation of the selected suggestion, which is the typed word.
+        let romanized = config
+            .get_romanization()
+            .then(|| romanized_candidate(&self.suggestions, 0))
+            .flatten();
+
+        // Smart punctuation.
+        if punctuation::is_enabled(config) {
+            for suggestion in self.suggestions.iter_mut() {
+                *suggestion.change_item() = punctuate(suggestion.to_string(), config);
+            }
+        }
+
+        // Reduce the number of suggestions and add the typed english word and the
+        // romanisation at the end. Also check that the typed text is not already
+        // included (may happen when the control characters are typed).
+        // The suggestions are not reduced when they are paginated.
+        let paginated = config.get_page_size() != 0;
+        let english = config.get_suggestion_include_english() && self.buffer != self.typed;
+        if !paginated {
             self.suggestions
-                .push(Rank::last_ranked(self.typed.clone(), 1));
-        } else {
-            self.suggestions.truncate(9);
+                .truncate(9 - usize::from(english) - usize::from(romanized.is_some()));
+        }
+        if english {
+            self.suggestions.push(Rank::last_ranked(
+                self.typed.clone(),
+                1,
+                CandidateKind::English,
+            ));
+        }
+        if let Some(romanized) = romanized {
+            push_checked(&mut self.suggestions, romanized);
         }
 
         Suggestion::new(
             self.buffer.clone(),
             &self.suggestions,
             0,
-            config.get_ansi_encoding(),
+            config.get_encoding(),
         )
     }
 
@@ -220,10 +334,13 @@ impl FixedMethod {
                     self.buffer.clone(),
                     &self.suggestions,
                     0,
-                    config.get_ansi_encoding(),
+                    config.get_encoding(),
                 )
             } else {
-                Suggestion::new_lonely(self.buffer.clone(), config.get_ansi_encoding())
+                Suggestion::new_lonely(
+                    normalize(&self.buffer, config.get_normalization()),
+                    config.get_encoding(),
+                )
             }
         } else {
             Suggestion::empty()
@@ -235,11 +352,15 @@ impl FixedMethod {
     fn process_key_value(&mut self, value: &str, config: &Config) {
         let rmc = self.buffer.chars().last().unwrap_or_default(); // Right most character
 
+        // The র of the layout is written as the ৰ in Assamese.
+        let ra = config.get_language().ra();
+        let value = &value.replace(B_R, ra.encode_utf8(&mut [0; 4]));
+
         // Zo-fola insertion
         if value == "\u{09CD}\u{09AF}" {
             // Check if র is not a part of a Ro-fola, if its not then add an ZWJ before
             // the Zo-fola to have the র‍্য form.
-            if rmc == B_R && self.buffer.chars().rev().nth(1).unwrap_or_default() != B_HASANTA {
+            if rmc == ra && self.buffer.chars().rev().nth(1).unwrap_or_default() != B_HASANTA {
                 self.buffer.push(ZWJ);
             }
             if config.get_fixed_old_kar_order() && is_left_standing_kar(rmc) {
@@ -254,8 +375,8 @@ impl FixedMethod {
         }
 
         // Old style Reph insertion
-        if value == "\u{09B0}\u{09CD}" && config.get_fixed_old_reph() {
-            self.insert_old_style_reph();
+        if value.chars().eq([ra, B_HASANTA]) && config.get_fixed_old_reph() {
+            self.insert_old_style_reph(ra);
             return;
         }
 
@@ -465,8 +586,8 @@ impl FixedMethod {
             || (right_most.is_vowel() && before_right_most.is_pure_consonant())
     }
 
-    /// Inserts Reph into the buffer in old style.
-    fn insert_old_style_reph(&mut self) {
+    /// Inserts Reph, made of the `ra`, into the buffer in old style.
+    fn insert_old_style_reph(&mut self, ra: char) {
         let len = self.buffer.chars().count();
         let reph_moveable = self.is_reph_moveable();
 
@@ -515,11 +636,11 @@ impl FixedMethod {
 
             let temp: String = self.buffer.chars().skip(len - step).collect();
             self.internal_backspace_step(step);
-            self.buffer.push(B_R);
+            self.buffer.push(ra);
             self.buffer.push(B_HASANTA);
             self.buffer.push_str(&temp);
         } else {
-            self.buffer.push(B_R);
+            self.buffer.push(ra);
             self.buffer.push(B_HASANTA);
         }
     }
@@ -550,6 +671,7 @@ impl Default for FixedMethod {
             pending_kar: None,
             suggestions: Vec::new(),
             layout,
+            quotes: Quotes::default(),
         }
     }
 }
@@ -565,18 +687,21 @@ mod tests {
     use pretty_assertions::assert_eq;
     
     use super::FixedMethod;
-    use crate::config::get_fixed_method_defaults;
+    use crate::config::{get_fixed_method_defaults, type_keys, Config};
     use crate::fixed::chars::*;
     use crate::{
-        context::Method,
+        context::{InputPurpose, Method},
         data::Data,
-        keycodes::{VC_A, VC_I, VC_K, VC_M, VC_PAREN_LEFT, VC_PAREN_RIGHT, VC_QUOTE},
+        keycodes::*,
+        language::Language,
+        normalization::Normalization,
+        suggestion::Suggestion,
     };
 
     #[test]
     fn test_suggestions() {
         let mut method = FixedMethod::default();
-        let config = get_fixed_method_defaults();
+        let mut config = get_fixed_method_defaults();
         let data = Data::new();
 
         method.buffer = "[".to_string();
@@ -602,6 +727,50 @@ mod tests {
             method.suggestions,
             ["কম্পিউ", "কম্পিউটার", "কম্পিউটিং", "কম্পিউটেশন", "কম্পিউটার্স"]
         );
+
+        // Numbers are spelled in words.
+        method.buffer = "(১২৫০)".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(
+            method.suggestions,
+            ["(১২৫০)", "(এক হাজার দুইশো পঞ্চাশ)", "(এক হাজার দুইশো পঞ্চাশতম)"]
+        );
+
+        // And formatted with the digit grouping.
+        config.set_number_formatting(true);
+        method.buffer = "৳১২৫০০০০".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions[..2], ["৳১২৫০০০০", "৳১২,৫০,০০০"]);
+
+        config.set_number_words(false);
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions, ["৳১২৫০০০০", "৳১২,৫০,০০০"]);
+        config.set_number_words(true);
+
+        // Smart punctuation.
+        config.set_punctuation_dari(true);
+        config.set_punctuation_double_dari(true);
+        method.buffer = "আমি।।".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions[0], "আমি॥");
+
+        method.buffer = "১২।৫".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions[0], "১২.৫");
+
+        // The equivalent spellings are suggested once.
+        config.set_normalization(Normalization::Bangla);
+        method.buffer = "প\u{09A1}\u{09BC}া".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions[0], "প\u{09DC}া");
+        assert_eq!(
+            method
+                .suggestions
+                .iter()
+                .filter(|s| **s == "প\u{09DC}া")
+                .count(),
+            1
+        );
     }
 
     #[test]
@@ -623,6 +792,30 @@ mod tests {
         assert_eq!(method.suggestions, ["()"]);
     }
 
+    #[test]
+    fn test_suggestions_with_romanization() {
+        let mut method = FixedMethod::default();
+        let mut config = get_fixed_method_defaults();
+        let data = Data::new();
+        config.set_suggestion_include_english(true);
+        config.set_romanization(true);
+
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(
+            method.suggestions,
+            ["আমি", "আমিন", "আমির", "আমিষ", "ami", "āmi"]
+        );
+        method.finish_input_session();
+
+        config.set_ansi_encoding(true);
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(method.suggestions, ["আমি", "আমিন", "আমির", "আমিষ"]);
+    }
+
     // The latest Rust version has incompatibility with the sorting order of the suggestions.
     // So, this sensitive test are disabled for the MSRV.
     #[rustversion::not(stable(1.75))]
@@ -763,35 +956,36 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::field_reassign_with_default)]
     fn test_reph_insertion() {
         let mut method = FixedMethod::default();
 
         method.buffer = "অক".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "অর্ক".to_string());
 
         method.buffer = "ক".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "র্ক".to_string());
 
         method.buffer = "কত".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "কর্ত".to_string());
 
         method.buffer = "অক্কা".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "অর্ক্কা".to_string());
 
         method.buffer = "কক্ষ্ম".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "কর্ক্ষ্ম".to_string());
 
         method.buffer = "কব্যা".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "কর্ব্যা".to_string());
 
         method.buffer = "কব্যাঁ".to_string();
-        method.insert_old_style_reph();
+        method.insert_old_style_reph(B_R);
         assert_eq!(method.buffer, "কর্ব্যাঁ".to_string());
     }
 
@@ -850,6 +1044,57 @@ mod tests {
         assert_eq!(method.buffer, "কা".to_string());
     }
 
+    #[test]
+    fn test_assamese() {
+        let mut method = FixedMethod::default();
+        let mut config = get_fixed_method_defaults();
+        let data = Data::new_with_language(Language::Assamese);
+        config.set_language(Language::Assamese);
+
+        // The র of the layout
+        method.buffer = "ঘ".to_string();
+        method.process_key_value("র", &config);
+        assert_eq!(method.buffer, "ঘৰ");
+
+        // Old style Reph
+        method.buffer = "অক".to_string();
+        method.process_key_value("র্", &config);
+        assert_eq!(method.buffer, "অৰ্ক");
+
+        method.buffer = "অৱ".to_string();
+        method.process_key_value("র্", &config);
+        assert_eq!(method.buffer, "অৰ্ৱ");
+
+        // Zo-fola
+        method.buffer = "ৰ".to_string();
+        method.process_key_value("্য", &config);
+        assert_eq!(method.buffer, "ৰ\u{200D}্য");
+
+        // Traditional Kar joining
+        method.buffer = "ৰ".to_string();
+        method.process_key_value(&B_U_KAR.to_string(), &config);
+        assert_eq!(method.buffer, "ৰ\u{200C}ু");
+
+        // Automatic Vowel Forming
+        method.buffer = "ৱ".to_string();
+        method.process_key_value(&B_AA_KAR.to_string(), &config);
+        assert_eq!(method.buffer, "ৱা");
+
+        method.buffer = "ৰা".to_string();
+        method.process_key_value(&B_I_KAR.to_string(), &config);
+        assert_eq!(method.buffer, "ৰাই");
+
+        // Suggestions from the Assamese dictionary
+        method.buffer = "ছোৱা".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions, ["ছোৱা", "ছোৱালী"]);
+
+        // Words missing from the Assamese dictionary are taken from the Bengali one.
+        method.buffer = "ৰাজনী".to_string();
+        method.create_dictionary_suggestion(&data, &config);
+        assert_eq!(method.suggestions, ["ৰাজনী", "ৰাজনীতি"]);
+    }
+
     #[test]
     fn test_z_zofola() {
         let mut method = FixedMethod::default();
@@ -1025,4 +1270,83 @@ mod tests {
         method.process_key_value("জ", &config);
         assert_eq!(method.buffer, "দর্জি".to_string());
     }
+
+    #[test]
+    fn test_quote_pairing() {
+        let mut config = get_fixed_method_defaults();
+        config.set_smart_quote(true);
+        let data = Data::new();
+        let mut method = FixedMethod::default();
+
+        let suggestion = type_keys(&mut method, &[VC_QUOTE, VC_K], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“ক");
+        method.candidate_committed(0, &config);
+
+        // The standalone quotation mark closes the committed one.
+        let suggestion = type_keys(&mut method, &[VC_QUOTE], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "”");
+        method.finish_input_session();
+
+        let suggestion = type_keys(&mut method, &[VC_QUOTE, VC_K], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“ক");
+        method.candidate_committed(0, &config);
+
+        // The open quotation is forgotten when the input session is finished.
+        method.finish_input_session();
+        let suggestion = type_keys(&mut method, &[VC_QUOTE], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“");
+    }
+
+    #[test]
+    fn test_input_purpose() {
+        let mut config = get_fixed_method_defaults();
+        config.set_suggestion_include_english(true);
+        let data = Data::new();
+        let mut method = FixedMethod::default();
+
+        config.set_input_purpose(InputPurpose::Url);
+        let suggestion = type_keys(
+            &mut method,
+            &[
+                VC_A, VC_COLON, VC_SLASH, VC_B, VC_PERIOD, VC_SLASH, VC_QUOTE,
+            ],
+            &data,
+            &config,
+        );
+        // The meta characters are kept in the URL.
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "a:/b./\"");
+
+        config.set_input_purpose(InputPurpose::Email);
+        let suggestion = type_keys(
+            &mut method,
+            &[VC_A, VC_AT, VC_B, VC_PERIOD, VC_C],
+            &data,
+            &config,
+        );
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "a@b.c");
+
+        config.set_input_purpose(InputPurpose::Password);
+        let suggestion = type_keys(&mut method, &[VC_A, VC_M, VC_I], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "ami");
+
+        config.set_input_purpose(InputPurpose::Number);
+        let suggestion = type_keys(&mut method, &[VC_1, VC_2, VC_PERIOD, VC_5], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "১২.৫");
+
+        config.set_input_purpose(InputPurpose::AsciiNumber);
+        let suggestion = type_keys(&mut method, &[VC_1, VC_2, VC_PERIOD, VC_5], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "12.5");
+
+        // Backspace keeps the typed text intact.
+        let suggestion = method.backspace_event(false, &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "12.");
+
+        config.set_input_purpose(InputPurpose::Search);
+        let suggestion = type_keys(&mut method, &[VC_SEMICOLON, VC_PAREN_RIGHT], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap(), [";)"]);
+
+        config.set_input_purpose(InputPurpose::Normal);
+        let suggestion = type_keys(&mut method, &[VC_SEMICOLON, VC_PAREN_RIGHT], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap(), [";)", "😉"]);
+    }
 }
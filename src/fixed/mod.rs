@@ -1,3 +1,3 @@
 mod chars;
-mod layout;
+pub mod layout;
 pub(crate) mod method;
@@ -0,0 +1,106 @@
+//! Languages written in the Bengali-Assamese script.
+//!
+//! Assamese shares the script with Bengali, but writes `ৰ` in place of the
+//! Bengali `র` and has `ৱ` for the `w` sound. The language selects the
+//! character set, the phonetic rules and the bundled data of the methods.
+
+use serde::{Deserialize, Serialize};
+
+use crate::utility::Utility;
+
+/// Language of the input.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    /// Bengali (Bangla).
+    #[default]
+    Bengali,
+    /// Assamese (Asamiya), written with `ৰ` and `ৱ`.
+    Assamese,
+}
+
+impl Language {
+    /// Get the `ra` letter of the language, `র` or `ৰ`.
+    pub fn ra(self) -> char {
+        match self {
+            Language::Bengali => '\u{09B0}',
+            Language::Assamese => '\u{09F0}',
+        }
+    }
+
+    /// Prepares the phonetic `text` for the transliteration.
+    ///
+    /// `x` is the Assamese sound of `স`, `শ` and `ষ`, so it's read as `s`.
+    pub(crate) fn phonetic_input(self, text: &str) -> String {
+        match self {
+            Language::Bengali => text.to_owned(),
+            Language::Assamese => text.replace(['x', 'X'], "s"),
+        }
+    }
+
+    /// Adapts the Bengali phonetic transliteration or the Bengali word `text`
+    /// to the language.
+    ///
+    /// For Assamese, `র` is written as `ৰ` and the `w` between the vowels,
+    /// transliterated as `ও` followed by a kar, as `ৱ`.
+    pub(crate) fn phonetic_output(self, text: &str) -> String {
+        match self {
+            Language::Bengali => text.to_owned(),
+            Language::Assamese => {
+                let mut output = String::with_capacity(text.len());
+                let mut chars = text.chars().peekable();
+
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\u{09B0}' => output.push('\u{09F0}'),
+                        'ও' if chars.peek().is_some_and(|c| c.is_kar()) => {
+                            output.push('\u{09F1}')
+                        }
+                        c => output.push(c),
+                    }
+                }
+
+                output
+            }
+        }
+    }
+
+    /// Spells the `text` of the language with the Bengali letters, to search
+    /// the Bengali dictionary with it.
+    ///
+    /// For Assamese, `ৰ` is spelled as `র` and `ৱ` as `ব`.
+    pub(crate) fn bengali_spelling(self, text: &str) -> String {
+        match self {
+            Language::Bengali => text.to_owned(),
+            Language::Assamese => text
+                .replace('\u{09F0}', "\u{09B0}")
+                .replace('\u{09F1}', "ব"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Language;
+
+    #[test]
+    fn test_phonetic() {
+        let output = |text| Language::Assamese.phonetic_output(text);
+
+        assert_eq!(Language::Assamese.phonetic_input("axom"), "asom");
+        assert_eq!(output("ঘর"), "ঘৰ");
+        assert_eq!(output("গুওাহাটি"), "গুৱাহাটি");
+        assert_eq!(output("হাওওা"), "হাওৱা");
+        assert_eq!(output("ও\u{09DF}া"), "ও\u{09DF}া");
+
+        assert_eq!(Language::Bengali.phonetic_input("axom"), "axom");
+        assert_eq!(Language::Bengali.phonetic_output("ঘর"), "ঘর");
+    }
+
+    #[test]
+    fn test_bengali_spelling() {
+        assert_eq!(Language::Assamese.bengali_spelling("ৰাৱণ"), "রাবণ");
+        assert_eq!(Language::Bengali.bengali_spelling("রাবণ"), "রাবণ");
+    }
+}
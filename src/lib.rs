@@ -1,11 +1,21 @@
 #![cfg_attr(feature = "bench", feature(test))]
 
+pub mod calendar;
 pub mod config;
 pub mod context;
-pub(crate) mod data;
+pub mod data;
+pub mod encoding;
+mod english;
 mod ffi;
-mod fixed;
+pub mod fixed;
 pub mod keycodes;
-mod phonetic;
+pub mod language;
+pub mod normalization;
+pub mod number;
+pub mod phonetic;
+pub mod profile;
+mod punctuation;
+pub mod romanization;
+pub mod store;
 pub mod suggestion;
 mod utility;
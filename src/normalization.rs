@@ -0,0 +1,141 @@
+//! Unicode normalisation of the Bengali text.
+//!
+//! Bengali has several canonically equivalent spellings:
+//! * `য়`, `ড়` and `ঢ়` are precomposed or their base letters followed by the nukta.
+//! * `ৎ` is itself or `ত` followed by the hasanta and a ZWJ.
+//! * `ো` and `ৌ` are themselves or their two parts, `ে` followed by `া` or `ৗ`.
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::suggestion::Rank;
+use crate::utility::push_checked;
+
+/// Letters which are decomposed by the NFC and their precomposed forms.
+const NUKTA_LETTERS: [(&str, &str); 3] = [
+    ("\u{09AF}\u{09BC}", "\u{09DF}"),
+    ("\u{09A1}\u{09BC}", "\u{09DC}"),
+    ("\u{09A2}\u{09BC}", "\u{09DD}"),
+];
+
+/// Target form of the normalisation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Normalization {
+    /// The text is kept as it is.
+    #[default]
+    None,
+    /// Unicode Normalization Form C. The nukta letters are decomposed, like `য়`
+    /// as `য` followed by the nukta.
+    Nfc,
+    /// The NFC, but the nukta letters are precomposed and `ৎ` is used instead
+    /// of `ত` followed by the hasanta and a ZWJ.
+    Bangla,
+}
+
+/// Normalises the `text` into the `form`.
+pub fn normalize(text: &str, form: Normalization) -> String {
+    match form {
+        Normalization::None => text.to_owned(),
+        Normalization::Nfc => text.nfc().collect(),
+        Normalization::Bangla => {
+            let mut text = text
+                .nfc()
+                .collect::<String>()
+                .replace("\u{09A4}\u{09CD}\u{200D}", "\u{09CE}");
+
+            for (decomposed, precomposed) in NUKTA_LETTERS {
+                text = text.replace(decomposed, precomposed);
+            }
+
+            text
+        }
+    }
+}
+
+/// Normalises the `suggestions` into the `form` and removes the duplicates,
+/// the first one is kept.
+pub(crate) fn normalize_suggestions(suggestions: &mut Vec<Rank>, form: Normalization) {
+    if form == Normalization::None {
+        return;
+    }
+
+    let mut normalized = Vec::with_capacity(suggestions.len());
+
+    for mut suggestion in suggestions.drain(..) {
+        *suggestion.change_item() = normalize(suggestion.to_string(), form);
+        push_checked(&mut normalized, suggestion);
+    }
+
+    *suggestions = normalized;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, normalize_suggestions, Normalization};
+    use crate::suggestion::{CandidateKind, Rank};
+
+    #[test]
+    fn test_nukta_letters() {
+        let precomposed =
+            "\u{09AC}\u{09BE}\u{09DF}\u{09C1} \u{09AA}\u{09DC}\u{09BE} \u{09AE}\u{09C2}\u{09DD}";
+        let decomposed = "\u{09AC}\u{09BE}\u{09AF}\u{09BC}\u{09C1} \u{09AA}\u{09A1}\u{09BC}\u{09BE} \u{09AE}\u{09C2}\u{09A2}\u{09BC}";
+
+        assert_eq!(normalize(precomposed, Normalization::None), precomposed);
+        assert_eq!(normalize(precomposed, Normalization::Nfc), decomposed);
+        assert_eq!(normalize(decomposed, Normalization::Nfc), decomposed);
+        assert_eq!(normalize(precomposed, Normalization::Bangla), precomposed);
+        assert_eq!(normalize(decomposed, Normalization::Bangla), precomposed);
+    }
+
+    #[test]
+    fn test_khanda_ta() {
+        let khanda_ta = "\u{09B9}\u{09A0}\u{09BE}\u{09CE}";
+        let joined = "\u{09B9}\u{09A0}\u{09BE}\u{09A4}\u{09CD}\u{200D}";
+
+        assert_eq!(normalize(joined, Normalization::Nfc), joined);
+        assert_eq!(normalize(joined, Normalization::Bangla), khanda_ta);
+        assert_eq!(normalize(khanda_ta, Normalization::Bangla), khanda_ta);
+    }
+
+    #[test]
+    fn test_two_part_vowel_signs() {
+        // কো and কৌ
+        let o = "\u{0995}\u{09CB}";
+        let au = "\u{0995}\u{09CC}";
+
+        for form in [Normalization::Nfc, Normalization::Bangla] {
+            assert_eq!(normalize("\u{0995}\u{09C7}\u{09BE}", form), o);
+            assert_eq!(normalize(o, form), o);
+            assert_eq!(normalize("\u{0995}\u{09C7}\u{09D7}", form), au);
+            assert_eq!(normalize(au, form), au);
+        }
+    }
+
+    #[test]
+    fn test_normalize_suggestions() {
+        let mut suggestions = vec![
+            Rank::first_ranked(
+                "\u{09AA}\u{09A1}\u{09BC}\u{09BE}".to_owned(),
+                CandidateKind::Typed,
+            ),
+            Rank::last_ranked(
+                "\u{09AA}\u{09DC}\u{09BE}".to_owned(),
+                1,
+                CandidateKind::Dictionary,
+            ),
+            Rank::last_ranked(
+                "\u{0995}\u{09C7}\u{09BE}".to_owned(),
+                2,
+                CandidateKind::Dictionary,
+            ),
+        ];
+
+        normalize_suggestions(&mut suggestions, Normalization::Bangla);
+        assert_eq!(
+            suggestions,
+            ["\u{09AA}\u{09DC}\u{09BE}", "\u{0995}\u{09CB}"]
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Spelling out numbers in Bengali words and formatting numbers.
+//!
+//! Numbers are grouped in the Indian way, `লাখ` for a hundred thousand and
+//! `কোটি` for ten million, and so are their digits, like `১২,৩৪,৫৬৭`.
+
+/// Words of the numbers below a hundred.
+#[rustfmt::skip]
+const NUMBERS: [&str; 100] = [
+    "শূন্য", "এক", "দুই", "তিন", "চার", "পাঁচ", "ছয়", "সাত", "আট", "নয়",
+    "দশ", "এগারো", "বারো", "তেরো", "চৌদ্দ", "পনেরো", "ষোলো", "সতেরো", "আঠারো", "উনিশ",
+    "বিশ", "একুশ", "বাইশ", "তেইশ", "চব্বিশ", "পঁচিশ", "ছাব্বিশ", "সাতাশ", "আটাশ", "ঊনত্রিশ",
+    "ত্রিশ", "একত্রিশ", "বত্রিশ", "তেত্রিশ", "চৌত্রিশ", "পঁয়ত্রিশ", "ছত্রিশ", "সাঁইত্রিশ", "আটত্রিশ", "ঊনচল্লিশ",
+    "চল্লিশ", "একচল্লিশ", "বিয়াল্লিশ", "তেতাল্লিশ", "চুয়াল্লিশ", "পঁয়তাল্লিশ", "ছেচল্লিশ", "সাতচল্লিশ", "আটচল্লিশ", "ঊনপঞ্চাশ",
+    "পঞ্চাশ", "একান্ন", "বায়ান্ন", "তিপ্পান্ন", "চুয়ান্ন", "পঞ্চান্ন", "ছাপ্পান্ন", "সাতান্ন", "আটান্ন", "ঊনষাট",
+    "ষাট", "একষট্টি", "বাষট্টি", "তেষট্টি", "চৌষট্টি", "পঁয়ষট্টি", "ছেষট্টি", "সাতষট্টি", "আটষট্টি", "ঊনসত্তর",
+    "সত্তর", "একাত্তর", "বাহাত্তর", "তিয়াত্তর", "চুয়াত্তর", "পঁচাত্তর", "ছিয়াত্তর", "সাতাত্তর", "আটাত্তর", "ঊনআশি",
+    "আশি", "একাশি", "বিরাশি", "তিরাশি", "চুরাশি", "পঁচাশি", "ছিয়াশি", "সাতাশি", "অষ্টআশি", "ঊননব্বই",
+    "নব্বই", "একানব্বই", "বিরানব্বই", "তিরানব্বই", "চুরানব্বই", "পঁচানব্বই", "ছিয়ানব্বই", "সাতানব্বই", "আটানব্বই", "নিরানব্বই",
+];
+
+/// Ordinals of the numbers from one to ten.
+const ORDINALS: [&str; 10] = [
+    "প্রথম",
+    "দ্বিতীয়",
+    "তৃতীয়",
+    "চতুর্থ",
+    "পঞ্চম",
+    "ষষ্ঠ",
+    "সপ্তম",
+    "অষ্টম",
+    "নবম",
+    "দশম",
+];
+
+const CRORE: u64 = 10_000_000;
+const LAKH: u64 = 100_000;
+
+/// Numbers with more digits than this aren't spelled.
+const MAX_DIGITS: usize = 18;
+
+/// Spells the `number` in words, like `1250` as `এক হাজার দুইশো পঞ্চাশ`.
+pub fn cardinal(number: u64) -> String {
+    if number == 0 {
+        return NUMBERS[0].to_owned();
+    }
+
+    let mut words = Vec::new();
+
+    // Crores are counted in words themselves, like `একশো কোটি`.
+    if number >= CRORE {
+        words.push(format!("{} কোটি", cardinal(number / CRORE)));
+    }
+
+    let rest = number % CRORE;
+    for (unit, name) in [(LAKH, "লাখ"), (1000, "হাজার")] {
+        let count = (rest / unit % 100) as usize;
+        if count != 0 {
+            words.push(format!("{} {name}", NUMBERS[count]));
+        }
+    }
+
+    let hundreds = (rest / 100 % 10) as usize;
+    if hundreds != 0 {
+        words.push(format!("{}শো", NUMBERS[hundreds]));
+    }
+
+    let rest = (rest % 100) as usize;
+    if rest != 0 {
+        words.push(NUMBERS[rest].to_owned());
+    }
+
+    words.join(" ")
+}
+
+/// Spells the ordinal of the `number`, like `1` as `প্রথম` and `25` as `পঁচিশতম`.
+///
+/// Returns `None` for zero, which has no ordinal.
+pub fn ordinal(number: u64) -> Option<String> {
+    match number {
+        0 => None,
+        1..=10 => Some(ORDINALS[number as usize - 1].to_owned()),
+        _ => Some(format!("{}তম", cardinal(number))),
+    }
+}
+
+/// Spells the number `text` in words, like `12.50` as `বারো দশমিক পাঁচ শূন্য`.
+///
+/// The digits can be ASCII or Bengali. The digits after the decimal point are
+/// spelled one by one. Returns `None` if `text` isn't a number.
+pub fn to_words(text: &str) -> Option<String> {
+    let (integer, fraction) = match text.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (text, None),
+    };
+
+    let mut words = cardinal(parse(integer)?);
+
+    if let Some(fraction) = fraction {
+        let digits = digits(fraction)?;
+        if digits.is_empty() {
+            return None;
+        }
+
+        words.push_str(" দশমিক");
+        for digit in digits {
+            words.push(' ');
+            words.push_str(NUMBERS[digit as usize]);
+        }
+    }
+
+    Some(words)
+}
+
+/// Spells the number `text` in words and, if it is a whole number, its ordinal.
+pub(crate) fn spell(text: &str) -> Vec<String> {
+    let Some(words) = to_words(text) else {
+        return Vec::new();
+    };
+
+    let mut spelled = vec![words];
+    if let Some(ordinal) = parse(text).and_then(ordinal) {
+        spelled.push(ordinal);
+    }
+
+    spelled
+}
+
+/// Currency prefixes of the numbers, formatted as `৳`.
+const CURRENCIES: [&str; 4] = ["৳", "tk", "Tk", "TK"];
+
+/// Digits of the formatted numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Digits {
+    /// Bengali digits, like `১২৩`.
+    Bengali,
+    /// ASCII digits, like `123`.
+    Ascii,
+}
+
+/// Converts the ASCII and Bengali digits of the `text` into the `digits`.
+///
+/// The other characters are kept as they are.
+pub fn convert_digits(text: &str, digits: Digits) -> String {
+    // Distance between the ASCII and the Bengali digits.
+    const OFFSET: u32 = '০' as u32 - '0' as u32;
+
+    text.chars()
+        .map(|c| match (c, digits) {
+            ('0'..='9', Digits::Bengali) => char::from_u32(c as u32 + OFFSET).unwrap(),
+            ('০'..='৯', Digits::Ascii) => char::from_u32(c as u32 - OFFSET).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Groups the digits of a whole number in the Indian way, like `1234567` as
+/// `12,34,567`.
+///
+/// The last three digits make the first group and the rest are grouped by two.
+pub fn group(digits: &str) -> String {
+    let count = digits.chars().count();
+    let mut grouped = String::with_capacity(digits.len() + count / 2);
+
+    for (index, c) in digits.chars().enumerate() {
+        let left = count - index;
+        if index != 0 && left >= 3 && left % 2 == 1 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+
+    grouped
+}
+
+/// Formats the number `text` with the Indian digit grouping, like `1234567.5`
+/// as `১২,৩৪,৫৬৭.৫`.
+///
+/// The digits can be ASCII or Bengali and are converted into the `digits`.
+/// Existing separators are regrouped. A currency prefix, `৳` or `tk`, is
+/// formatted as `৳` and a percent sign is kept. Returns `None` if `text`
+/// isn't a number.
+pub fn format(text: &str, digits: Digits) -> Option<String> {
+    let (currency, text) = match CURRENCIES.iter().find_map(|c| text.strip_prefix(c)) {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (percent, text) = match text.strip_suffix('%') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (integer, fraction) = match text.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (text, None),
+    };
+    let integer: String = integer.chars().filter(|&c| c != ',').collect();
+
+    if (currency && percent) || !is_number(&integer) || !fraction.map_or(true, is_number) {
+        return None;
+    }
+
+    let mut formatted = String::with_capacity(text.len() * 2);
+
+    if currency {
+        formatted.push('৳');
+    }
+    if negative {
+        formatted.push('-');
+    }
+    formatted.push_str(&convert_digits(&group(&integer), digits));
+    if let Some(fraction) = fraction {
+        formatted.push('.');
+        formatted.push_str(&convert_digits(fraction, digits));
+    }
+    if percent {
+        formatted.push('%');
+    }
+
+    Some(formatted)
+}
+
+/// Checks if the `text` is made of ASCII or Bengali digits only.
+fn is_number(text: &str) -> bool {
+    digits(text).is_some_and(|digits| !digits.is_empty())
+}
+
+/// Parses a whole number of ASCII or Bengali digits.
+fn parse(text: &str) -> Option<u64> {
+    let digits = digits(text)?;
+
+    if digits.is_empty() || digits.len() > MAX_DIGITS {
+        return None;
+    }
+
+    Some(
+        digits
+            .into_iter()
+            .fold(0, |number, digit| number * 10 + digit),
+    )
+}
+
+/// Returns the values of the ASCII or Bengali `digits`.
+fn digits(text: &str) -> Option<Vec<u64>> {
+    text.chars()
+        .map(|c| match c {
+            '0'..='9' => c.to_digit(10).map(u64::from),
+            '০'..='৯' => Some(c as u64 - '০' as u64),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cardinal, convert_digits, format, group, ordinal, spell, to_words, Digits};
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(cardinal(0), "শূন্য");
+        assert_eq!(cardinal(7), "সাত");
+        assert_eq!(cardinal(99), "নিরানব্বই");
+        assert_eq!(cardinal(100), "একশো");
+        assert_eq!(cardinal(1250), "এক হাজার দুইশো পঞ্চাশ");
+        assert_eq!(cardinal(205_000), "দুই লাখ পাঁচ হাজার");
+        assert_eq!(
+            cardinal(34_567_891),
+            "তিন কোটি পঁয়তাল্লিশ লাখ সাতষট্টি হাজার আটশো একানব্বই"
+        );
+        assert_eq!(cardinal(1_500_000_000), "একশো পঞ্চাশ কোটি");
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal(0), None);
+        assert_eq!(ordinal(1).unwrap(), "প্রথম");
+        assert_eq!(ordinal(2).unwrap(), "দ্বিতীয়");
+        assert_eq!(ordinal(10).unwrap(), "দশম");
+        assert_eq!(ordinal(25).unwrap(), "পঁচিশতম");
+    }
+
+    #[test]
+    fn test_to_words() {
+        assert_eq!(to_words("1250").unwrap(), "এক হাজার দুইশো পঞ্চাশ");
+        assert_eq!(to_words("১২৫০").unwrap(), "এক হাজার দুইশো পঞ্চাশ");
+        assert_eq!(to_words("12.50").unwrap(), "বারো দশমিক পাঁচ শূন্য");
+        assert_eq!(to_words("0.5").unwrap(), "শূন্য দশমিক পাঁচ");
+        assert_eq!(to_words("12."), None);
+        assert_eq!(to_words(".5"), None);
+        assert_eq!(to_words("1.2.3"), None);
+        assert_eq!(to_words("12a"), None);
+        assert_eq!(to_words("1234567890123456789"), None);
+
+        assert_eq!(spell("3"), ["তিন", "তৃতীয়"]);
+        assert_eq!(spell("3.5"), ["তিন দশমিক পাঁচ"]);
+        assert!(spell("abc").is_empty());
+    }
+
+    #[test]
+    fn test_group() {
+        assert_eq!(group(""), "");
+        assert_eq!(group("123"), "123");
+        assert_eq!(group("1234"), "1,234");
+        assert_eq!(group("1234567"), "12,34,567");
+        assert_eq!(group("১২৩৪৫৬৭৮"), "১,২৩,৪৫,৬৭৮");
+    }
+
+    #[test]
+    fn test_convert_digits() {
+        assert_eq!(convert_digits("1234567890", Digits::Bengali), "১২৩৪৫৬৭৮৯০");
+        assert_eq!(convert_digits("-12.50", Digits::Bengali), "-১২.৫০");
+        assert_eq!(convert_digits("১২", Digits::Bengali), "১২");
+        assert_eq!(convert_digits("tk 1250", Digits::Bengali), "tk ১২৫০");
+        assert_eq!(convert_digits("১২.৫%", Digits::Ascii), "12.5%");
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format("1234567", Digits::Bengali).unwrap(), "১২,৩৪,৫৬৭");
+        assert_eq!(format("১২৩৪৫৬৭.৫০", Digits::Ascii).unwrap(), "12,34,567.50");
+        assert_eq!(format("1,234,567", Digits::Bengali).unwrap(), "১২,৩৪,৫৬৭");
+        assert_eq!(format("-12345", Digits::Bengali).unwrap(), "-১২,৩৪৫");
+        assert_eq!(format("tk5000", Digits::Bengali).unwrap(), "৳৫,০০০");
+        assert_eq!(format("৳১০০০০০", Digits::Ascii).unwrap(), "৳1,00,000");
+        assert_eq!(format("12.5%", Digits::Bengali).unwrap(), "১২.৫%");
+        assert_eq!(format("tk50%", Digits::Bengali), None);
+        assert_eq!(format("12.", Digits::Bengali), None);
+        assert_eq!(format("tk", Digits::Bengali), None);
+        assert_eq!(format("12a", Digits::Bengali), None);
+    }
+}
@@ -1,23 +1,26 @@
 // Phonetic Method
 use ahash::RandomState;
 use std::collections::HashMap;
-use std::fs::{write, File};
 use std::time::SystemTime;
 
 use crate::config::Config;
-use crate::context::Method;
+use crate::context::{InputPurpose, Method};
 use crate::data::Data;
+use crate::encoding::Encoding;
 use crate::keycodes::keycode_to_char;
+use crate::normalization::normalize;
+use crate::number::{convert_digits, Digits};
 use crate::phonetic::suggestion::PhoneticSuggestion;
-use crate::suggestion::Suggestion;
-use crate::utility::{read, SplittedString};
+use crate::store::{PHONETIC_AUTOCORRECT, PHONETIC_SELECTIONS};
+use crate::suggestion::{CandidateKind, Suggestion};
+use crate::utility::{Quotes, SplittedString};
 
 pub(crate) struct PhoneticMethod {
     buffer: String,
     suggestion: PhoneticSuggestion,
     // Candidate selections.
     selections: HashMap<String, String, RandomState>,
-    // Last modification of the user's auto correct file.
+    // Last modification of the user's auto correct entries.
     modified: SystemTime,
     // Previously selected candidate index of the current suggestion list.
     prev_selection: usize,
@@ -26,19 +29,21 @@ pub(crate) struct PhoneticMethod {
 impl PhoneticMethod {
     /// Creates a new `PhoneticMethod` struct.
     pub(crate) fn new(config: &Config) -> Self {
-        // Load candidate selections file.
-        let selections = if let Ok(file) = std::fs::read(config.get_user_phonetic_selection_data())
-        {
-            serde_json::from_slice(&file).unwrap()
-        } else {
-            HashMap::with_hasher(RandomState::new())
-        };
+        let store = config.get_user_store();
 
-        // Load user's auto correct file.
+        // Load candidate selections. Corrupted entries are started over.
+        let selections = store
+            .read(PHONETIC_SELECTIONS)
+            .and_then(|data| serde_json::from_slice(&data).ok())
+            .unwrap_or_default();
+
+        // Load user's auto correct entries.
         let (modified, autocorrect) = {
-            if let Ok(mut file) = File::open(config.get_user_phonetic_autocorrect()) {
-                let modified = file.metadata().unwrap().modified().unwrap();
-                let autocorrect = serde_json::from_slice(&read(&mut file)).unwrap();
+            if let Some(data) = store.read(PHONETIC_AUTOCORRECT) {
+                let modified = store
+                    .modified(PHONETIC_AUTOCORRECT)
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                let autocorrect = serde_json::from_slice(&data).unwrap_or_default();
                 (modified, autocorrect)
             } else {
                 (
@@ -48,9 +53,12 @@ impl PhoneticMethod {
             }
         };
 
+        let mut suggestion = PhoneticSuggestion::new(autocorrect);
+        suggestion.set_language(config.get_language());
+
         PhoneticMethod {
             buffer: String::with_capacity(20),
-            suggestion: PhoneticSuggestion::new(autocorrect),
+            suggestion,
             selections,
             modified,
             prev_selection: 0,
@@ -59,7 +67,16 @@ impl PhoneticMethod {
 
     /// Returns `Suggestion` struct with suggestions.
     fn create_suggestion(&mut self, data: &Data, config: &Config) -> Suggestion {
-        if config.get_phonetic_suggestion() {
+        let purpose = config.get_input_purpose();
+
+        if purpose.is_passthrough() || purpose == InputPurpose::AsciiNumber {
+            Suggestion::new_lonely(self.buffer.clone(), Encoding::Unicode)
+        } else if purpose == InputPurpose::Number {
+            Suggestion::new_lonely(
+                convert_digits(&self.buffer, Digits::Bengali),
+                config.get_encoding(),
+            )
+        } else if config.get_phonetic_suggestion() {
             let (suggestions, selection) =
                 self.suggestion
                     .suggest(&self.buffer, data, &mut self.selections, config);
@@ -70,12 +87,20 @@ impl PhoneticMethod {
                 self.buffer.clone(),
                 &suggestions,
                 self.prev_selection,
-                config.get_ansi_encoding(),
+                config.get_encoding(),
             )
         } else {
-            let suggestion = self.suggestion.suggest_only_phonetic(&self.buffer);
+            let suggestion = if config.get_phonetic_case_insensitive() {
+                self.suggestion
+                    .suggest_only_phonetic(&self.buffer.to_ascii_lowercase())
+            } else {
+                self.suggestion.suggest_only_phonetic(&self.buffer)
+            };
 
-            Suggestion::new_lonely(suggestion, config.get_ansi_encoding())
+            Suggestion::new_lonely(
+                normalize(&suggestion, config.get_normalization()),
+                config.get_encoding(),
+            )
         }
     }
 }
@@ -111,23 +136,47 @@ impl Method for PhoneticMethod {
     }
 
     fn candidate_committed(&mut self, index: usize, config: &Config) {
-        // Check if user has selected a different suggestion
-        if self.prev_selection != index && config.get_phonetic_suggestion() {
-            let suggestion =
-                SplittedString::split(self.suggestion.suggestions[index].to_string(), true)
+        let purpose = config.get_input_purpose();
+
+        // Check if user has selected a different suggestion.
+        // Nothing is learned in the private mode or from a field without conversion.
+        if self.prev_selection != index
+            && config.get_phonetic_suggestion()
+            && !config.get_private_mode()
+            && !purpose.is_passthrough()
+            && !purpose.is_numeric()
+        {
+            // The romanisation, the numbers and the dates are made from the
+            // typed text each time, so they aren't learned.
+            if let Some(selected) = self.suggestion.suggestions.get(index).filter(|selected| {
+                !matches!(
+                    selected.kind(),
+                    CandidateKind::Romanized | CandidateKind::Number | CandidateKind::DateTime
+                )
+            }) {
+                let suggestion = SplittedString::split(selected.to_string(), true)
                     .word()
                     .to_string();
-            self.selections.insert(
-                SplittedString::split(&self.buffer, false)
-                    .word()
-                    .to_string(),
-                suggestion,
-            );
-            write(
-                config.get_user_phonetic_selection_data(),
-                serde_json::to_string(&self.selections).unwrap(),
-            )
-            .unwrap();
+                self.selections.insert(
+                    SplittedString::split(&self.buffer, false)
+                        .word()
+                        .to_string(),
+                    suggestion,
+                );
+                // Failing to persist the selections (e.g. a read-only store)
+                // only loses the learning, so it is ignored.
+                let _ = config.get_user_store().write(
+                    PHONETIC_SELECTIONS,
+                    serde_json::to_string(&self.selections).unwrap().as_bytes(),
+                );
+            }
+        }
+
+        // Pair the following standalone quotation marks with the committed text.
+        if config.get_phonetic_suggestion() && !purpose.is_passthrough() && !purpose.is_numeric() {
+            if let Some(committed) = self.suggestion.suggestions.get(index) {
+                self.suggestion.quotes.update(committed.to_string());
+            }
         }
 
         // Reset to defaults
@@ -135,13 +184,18 @@ impl Method for PhoneticMethod {
     }
 
     fn update_engine(&mut self, config: &Config) {
-        if let Ok(mut file) = File::open(config.get_user_phonetic_autocorrect()) {
-            let modified = file.metadata().unwrap().modified().unwrap();
-            // Update the auto correct entries if only the file was modified in the meantime.
+        let store = config.get_user_store();
+
+        self.suggestion.set_language(config.get_language());
+
+        if let Some(modified) = store.modified(PHONETIC_AUTOCORRECT) {
+            // Update the auto correct entries if only they were modified in the meantime.
             if modified > self.modified {
-                self.suggestion.user_autocorrect =
-                    serde_json::from_slice(&read(&mut file)).unwrap();
-                self.modified = modified;
+                if let Some(data) = store.read(PHONETIC_AUTOCORRECT) {
+                    self.suggestion
+                        .set_user_autocorrect(serde_json::from_slice(&data).unwrap_or_default());
+                    self.modified = modified;
+                }
             }
         }
     }
@@ -152,6 +206,8 @@ impl Method for PhoneticMethod {
 
     fn finish_input_session(&mut self) {
         self.buffer.clear();
+        // The following text may not be written after the committed one.
+        self.suggestion.quotes = Quotes::default();
     }
 
     fn backspace_event(&mut self, ctrl: bool, data: &Data, config: &Config) -> Suggestion {
@@ -192,11 +248,19 @@ impl Default for PhoneticMethod {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::time::SystemTime;
+
     use super::PhoneticMethod;
-    use crate::config::get_phonetic_method_defaults;
-    use crate::context::Method;
+    use crate::config::{get_phonetic_method_defaults, type_keys};
+    use crate::context::{InputPurpose, Method};
     use crate::data::Data;
-    use crate::keycodes::{VC_COMMA, VC_R};
+    use crate::keycodes::{
+        VC_1, VC_2, VC_5, VC_A, VC_AT, VC_B, VC_C, VC_COLON, VC_COMMA, VC_E, VC_I, VC_L, VC_M,
+        VC_PERIOD, VC_QUOTE, VC_R, VC_S, VC_SLASH, VC_T_SHIFT,
+    };
+    use crate::store::{MemoryStore, UserStore, PHONETIC_AUTOCORRECT, PHONETIC_SELECTIONS};
+    use crate::suggestion::CandidateKind;
 
     #[test]
     fn test_backspace() {
@@ -228,11 +292,226 @@ mod tests {
         };
 
         let suggestion = method.get_suggestion(VC_COMMA, 0, 3, &data, &config);
-        assert_eq!(suggestion.previously_selected_index(), 3);
+        assert_eq!(suggestion.previously_selected_index().unwrap(), 3);
 
         method.backspace_event(false, &data, &config);
 
         let suggestion = method.get_suggestion(VC_R, 0, 3, &data, &config);
-        assert_eq!(suggestion.previously_selected_index(), 0);
+        assert_eq!(suggestion.previously_selected_index().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_user_store() {
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        let store = Arc::new(MemoryStore::new());
+        config.set_user_store(store.clone());
+
+        let mut method = PhoneticMethod::new(&config);
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[1], "আমই");
+        method.candidate_committed(1, &config);
+
+        assert_eq!(
+            store.read(PHONETIC_SELECTIONS).unwrap(),
+            r#"{"ami":"আমই"}"#.as_bytes()
+        );
+
+        // The selection is loaded back from the store.
+        let mut method = PhoneticMethod::new(&config);
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(suggestion.previously_selected_index().unwrap(), 1);
+        method.finish_input_session();
+
+        // User's auto correct entries are reloaded when modified.
+        store
+            .write(PHONETIC_AUTOCORRECT, br#"{"ami":"amio"}"#)
+            .unwrap();
+        method.update_engine(&config);
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "আমিও");
+    }
+
+    #[test]
+    fn test_generated_candidates_not_learned() {
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        let store = Arc::new(MemoryStore::new());
+        config.set_user_store(store.clone());
+        config.set_romanization(true);
+
+        let mut method = PhoneticMethod::new(&config);
+        for (keys, kind) in [
+            (&[VC_A, VC_M, VC_I][..], CandidateKind::Romanized),
+            (&[VC_1, VC_2], CandidateKind::Number),
+        ] {
+            let suggestion = type_keys(&mut method, keys, &data, &config);
+            let index = suggestion
+                .get_candidates()
+                .unwrap()
+                .iter()
+                .position(|candidate| candidate.kind == kind)
+                .unwrap();
+            assert_ne!(index, 0);
+            method.candidate_committed(index, &config);
+        }
+
+        assert!(store.read(PHONETIC_SELECTIONS).is_none());
+    }
+
+    /// A store with corrupted entries which can't be written.
+    #[derive(Debug)]
+    struct BrokenStore;
+
+    impl UserStore for BrokenStore {
+        fn read(&self, _: &str) -> Option<Vec<u8>> {
+            Some(b"{corrupted".to_vec())
+        }
+
+        fn write(&self, _: &str, _: &[u8]) -> std::io::Result<()> {
+            Err(std::io::ErrorKind::PermissionDenied.into())
+        }
+
+        fn modified(&self, _: &str) -> Option<SystemTime> {
+            Some(SystemTime::now())
+        }
+    }
+
+    #[test]
+    fn test_broken_user_store() {
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        config.set_user_store(Arc::new(BrokenStore));
+
+        let mut method = PhoneticMethod::new(&config);
+        method.update_engine(&config);
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[1], "আমই");
+        method.candidate_committed(1, &config);
+
+        // The selection is still learned for the session.
+        method.get_suggestion(VC_A, 0, 0, &data, &config);
+        method.get_suggestion(VC_M, 0, 0, &data, &config);
+        let suggestion = method.get_suggestion(VC_I, 0, 0, &data, &config);
+        assert_eq!(suggestion.previously_selected_index().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_input_purpose() {
+        let mut config = get_phonetic_method_defaults();
+        config.set_suggestion_include_english(true);
+        let data = Data::new();
+        let mut method = PhoneticMethod::default();
+
+        config.set_input_purpose(InputPurpose::Url);
+        let suggestion = type_keys(
+            &mut method,
+            &[
+                VC_A, VC_COLON, VC_SLASH, VC_B, VC_PERIOD, VC_SLASH, VC_QUOTE,
+            ],
+            &data,
+            &config,
+        );
+        // The meta characters are kept in the URL.
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "a:/b./\"");
+
+        config.set_input_purpose(InputPurpose::Email);
+        let suggestion = type_keys(
+            &mut method,
+            &[VC_A, VC_AT, VC_B, VC_PERIOD, VC_C],
+            &data,
+            &config,
+        );
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "a@b.c");
+
+        config.set_input_purpose(InputPurpose::Password);
+        let suggestion = type_keys(&mut method, &[VC_A, VC_M, VC_I], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "ami");
+
+        config.set_input_purpose(InputPurpose::Number);
+        let suggestion = type_keys(&mut method, &[VC_1, VC_2, VC_PERIOD, VC_5], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "১২.৫");
+
+        config.set_input_purpose(InputPurpose::AsciiNumber);
+        let suggestion = type_keys(&mut method, &[VC_1, VC_2, VC_PERIOD, VC_5], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "12.5");
+
+        config.set_input_purpose(InputPurpose::Search);
+        let suggestion = type_keys(&mut method, &[VC_S, VC_M, VC_I, VC_L, VC_E], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["স্মিলে"]);
+
+        config.set_input_purpose(InputPurpose::Normal);
+        let suggestion = type_keys(&mut method, &[VC_S, VC_M, VC_I, VC_L, VC_E], &data, &config);
+        assert_eq!(
+            suggestion.get_suggestions().unwrap(),
+            ["😀", "😄", "স্মিলে", "smile"]
+        );
+        assert_eq!(
+            suggestion
+                .get_candidates()
+                .unwrap()
+                .iter()
+                .map(|c| c.kind)
+                .collect::<Vec<_>>(),
+            [
+                CandidateKind::Emoji,
+                CandidateKind::Emoji,
+                CandidateKind::Phonetic,
+                CandidateKind::English
+            ]
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_without_suggestion() {
+        let mut config = get_phonetic_method_defaults();
+        config.set_phonetic_suggestion(false);
+        let data = Data::new();
+        let mut method = PhoneticMethod::default();
+
+        let suggestion = type_keys(&mut method, &[VC_T_SHIFT, VC_A], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "টা");
+
+        config.set_phonetic_case_insensitive(true);
+        let suggestion = type_keys(&mut method, &[VC_T_SHIFT, VC_A], &data, &config);
+        assert_eq!(suggestion.get_lonely_suggestion().unwrap(), "তা");
+    }
+
+    #[test]
+    fn test_quote_pairing() {
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        let mut method = PhoneticMethod::default();
+
+        let suggestion = type_keys(&mut method, &[VC_QUOTE, VC_A, VC_M, VC_I], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“আমি");
+        method.candidate_committed(0, &config);
+
+        // The standalone quotation mark closes the committed one.
+        let suggestion = type_keys(&mut method, &[VC_QUOTE], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "”");
+        method.candidate_committed(0, &config);
+
+        let suggestion = type_keys(&mut method, &[VC_QUOTE], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“");
+        method.candidate_committed(0, &config);
+
+        // The open quotation is forgotten when the input session is finished.
+        method.finish_input_session();
+        let suggestion = type_keys(&mut method, &[VC_QUOTE], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "“");
+        method.finish_input_session();
+
+        config.set_smart_quote_bengali(true);
+        let suggestion = type_keys(&mut method, &[VC_QUOTE], &data, &config);
+        assert_eq!(suggestion.get_suggestions().unwrap()[0], "‘");
     }
 }
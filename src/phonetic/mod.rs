@@ -1,2 +1,68 @@
+use std::collections::HashMap;
+
+use okkhor::parser::Parser;
+
+use crate::config::Config;
+use crate::data::Data;
+use crate::store::PHONETIC_AUTOCORRECT;
+
 pub(crate) mod method;
 mod suggestion;
+
+/// Transliterate the `text` into Bengali using the Avro Phonetic scheme.
+///
+/// Only the phonetic conversion is done, neither the dictionary nor the
+/// Auto Correct entries are used.
+pub fn transliterate(text: &str) -> String {
+    Parser::new_phonetic().convert(text)
+}
+
+/// Search the `term` in the bundled Auto Correct dictionary.
+///
+/// Returns the replacement in the Avro Phonetic notation, it needs to be
+/// transliterated with [`transliterate`] to get the Bengali text.
+pub fn search_autocorrect(term: &str) -> Option<String> {
+    Data::new().search_corrected(term).map(str::to_owned)
+}
+
+/// Search the `term` in the user's own Auto Correct entries, which are read
+/// from the user store of the `config`.
+///
+/// The user's entries take precedence over the bundled ones in the suggestions.
+pub fn search_user_autocorrect(term: &str, config: &Config) -> Option<String> {
+    let data = config.get_user_store().read(PHONETIC_AUTOCORRECT)?;
+    let mut entries: HashMap<String, String> = serde_json::from_slice(&data).ok()?;
+    entries.remove(term)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{search_autocorrect, search_user_autocorrect, transliterate};
+    use crate::config::get_phonetic_method_defaults;
+    use crate::store::{MemoryStore, UserStore, PHONETIC_AUTOCORRECT};
+
+    #[test]
+    fn test_transliterate() {
+        assert_eq!(transliterate("ami gan gai."), "আমি গান গাই।");
+        assert_eq!(transliterate(""), "");
+    }
+
+    #[test]
+    fn test_search_autocorrect() {
+        assert_eq!(search_autocorrect("academy").unwrap(), "oZakaDemi");
+        assert_eq!(search_autocorrect("\\nai\\"), None);
+
+        let mut config = get_phonetic_method_defaults();
+        let store = Arc::new(MemoryStore::new());
+        config.set_user_store(store.clone());
+        assert_eq!(search_user_autocorrect("ami", &config), None);
+
+        store
+            .write(PHONETIC_AUTOCORRECT, br#"{"ami":"amio"}"#)
+            .unwrap();
+        assert_eq!(search_user_autocorrect("ami", &config).unwrap(), "amio");
+        assert_eq!(search_user_autocorrect("academy", &config), None);
+    }
+}
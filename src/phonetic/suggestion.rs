@@ -5,10 +5,16 @@ use okkhor::parser::Parser;
 use std::collections::HashMap;
 use upodesh::avro::Suggest;
 
+use crate::calendar;
 use crate::config::Config;
 use crate::data::Data;
-use crate::suggestion::Rank;
-use crate::utility::{push_checked, smart_quoter, SplittedString, Utility};
+use crate::language::Language;
+use crate::normalization::normalize_suggestions;
+use crate::number::{self, Digits};
+use crate::punctuation::{self, punctuate};
+use crate::romanization::romanized_candidate;
+use crate::suggestion::{CandidateKind, Rank};
+use crate::utility::{push_checked, smart_quoter, Quotes, SplittedString, Utility};
 
 pub(crate) struct PhoneticSuggestion {
     pub(crate) suggestions: Vec<Rank>,
@@ -19,8 +25,12 @@ pub(crate) struct PhoneticSuggestion {
     cache: HashMap<String, Vec<Rank>, RandomState>,
     phonetic: Parser,
     dict: Suggest,
+    // Language whose phonetic rules and dictionary are used.
+    language: Language,
     // The user's auto-correct entries.
-    pub(crate) user_autocorrect: HashMap<String, String, RandomState>,
+    user_autocorrect: HashMap<String, String, RandomState>,
+    // Open quotations of the committed text.
+    pub(crate) quotes: Quotes,
 }
 
 impl PhoneticSuggestion {
@@ -31,10 +41,37 @@ impl PhoneticSuggestion {
             cache: HashMap::with_capacity_and_hasher(20, RandomState::new()),
             phonetic: Parser::new_phonetic(),
             dict: Suggest::new(),
+            language: Language::Bengali,
             user_autocorrect,
+            quotes: Quotes::default(),
         }
     }
 
+    /// Replace the user's auto-correct entries.
+    ///
+    /// The cached dictionary searches are dropped as they may contain stale auto-correct items.
+    pub(crate) fn set_user_autocorrect(&mut self, entries: HashMap<String, String, RandomState>) {
+        self.user_autocorrect = entries;
+        self.cache.clear();
+    }
+
+    /// Set the language of the suggestions.
+    ///
+    /// The cached dictionary searches are dropped if the language is changed.
+    pub(crate) fn set_language(&mut self, language: Language) {
+        if self.language != language {
+            self.language = language;
+            self.cache.clear();
+        }
+    }
+
+    /// Transliterate the phonetic `text` by the rules of the language.
+    fn transliterate(&self, text: &str) -> String {
+        let mut output = String::new();
+        transliterate_into(&self.phonetic, self.language, text, &mut output);
+        output
+    }
+
     /// Add suffix(গুলো, মালা, etc.) to the dictionary suggestions and return them.
     ///
     /// This function gets the suggestion list from the stored cache.
@@ -79,6 +116,7 @@ impl PhoneticSuggestion {
                             let mut new = base.clone();
                             // This changes the suggestion with the suffixed one while keeping the ranking intact.
                             *new.change_item() = word;
+                            new.set_kind(CandidateKind::Suffixed);
                             list.push(new);
                         }
                     }
@@ -93,7 +131,12 @@ impl PhoneticSuggestion {
     pub(crate) fn suggest_only_phonetic(&mut self, term: &str) -> String {
         let string = SplittedString::split(term, false);
 
-        self.phonetic.convert_into(string.word(), &mut self.pbuffer);
+        transliterate_into(
+            &self.phonetic,
+            self.language,
+            string.word(),
+            &mut self.pbuffer,
+        );
 
         format!(
             "{}{}{}",
@@ -118,18 +161,37 @@ impl PhoneticSuggestion {
 
         // Smart Quoting feature
         if config.get_smart_quote() {
-            string = smart_quoter(string);
+            string = smart_quoter(string, self.quotes, config.get_smart_quote_bengali());
         }
 
         self.suggestion_with_dict(&string, data);
 
-        // Emoji addition with corresponding emoticon (if ANSI mode is not enabled).
-        if !config.get_ansi_encoding() {
+        // Case-insensitive input: Include the suggestions of the lower cased word,
+        // if its reading differs. Both are ranked by their edit distance when
+        // sorted, and the typed word's ones go first when they're tied.
+        if config.get_phonetic_case_insensitive() {
+            let folded = term.to_ascii_lowercase();
+            let folded = SplittedString::split(&folded, false);
+
+            if self.transliterate(folded.word()) != self.pbuffer {
+                let typed = std::mem::take(&mut self.suggestions);
+                self.suggestion_with_dict(&folded, data);
+
+                let folded = std::mem::replace(&mut self.suggestions, typed);
+                for suggestion in folded {
+                    push_checked(&mut self.suggestions, suggestion);
+                }
+            }
+        }
+
+        // Emoji addition with corresponding emoticon (if Emoji suggestion is enabled).
+        if config.get_suggestion_include_emoji() {
             if let Some(emoji) = data.get_emoji_by_emoticon(term) {
                 // Add the emoticon
                 self.suggestions.push(Rank::emoji(emoji.to_owned()));
                 // Add the full term as the last ranked suggestion.
-                self.suggestions.push(Rank::last_ranked(term.to_owned(), 1));
+                self.suggestions
+                    .push(Rank::last_ranked(term.to_owned(), 1, CandidateKind::Typed));
                 // Mark that we have added the typed text already (as the emoticon).
                 typed_added = true;
             } else {
@@ -184,23 +246,107 @@ impl PhoneticSuggestion {
                     string.trailing()
                 ),
                 2,
+                CandidateKind::Phonetic,
             ),
         );
 
+        // The typed number with the digit grouping, `$` is typed for `৳`.
+        if config.get_number_formatting() {
+            let typed = term.replacen('$', "৳", 1);
+            if let Some(formatted) = number::format(&typed, Digits::Bengali) {
+                push_checked(
+                    &mut self.suggestions,
+                    Rank::last_ranked(formatted, 2, CandidateKind::Number),
+                );
+            }
+        }
+
+        // Spell the typed number in words.
+        let mut extra: Vec<_> = if config.get_number_words() {
+            number::spell(string.word())
+                .into_iter()
+                .map(|words| (words, CandidateKind::Number))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // The current date or time for the trigger words.
+        let triggered = |triggers: &[String]| {
+            triggers
+                .iter()
+                .any(|trigger| trigger.eq_ignore_ascii_case(string.word()))
+        };
+        if triggered(config.get_date_triggers()) {
+            let dates = calendar::date_suggestions(&config.get_clock().now());
+            extra.extend(
+                dates
+                    .into_iter()
+                    .map(|date| (date, CandidateKind::DateTime)),
+            );
+        }
+        if triggered(config.get_time_triggers()) {
+            let times = calendar::time_suggestions(&config.get_clock().now());
+            extra.extend(
+                times
+                    .into_iter()
+                    .map(|time| (time, CandidateKind::DateTime)),
+            );
+        }
+
+        for (text, kind) in extra {
+            push_checked(
+                &mut self.suggestions,
+                Rank::last_ranked(
+                    format!("{}{}{}", string.preceding(), text, string.trailing()),
+                    2,
+                    kind,
+                ),
+            );
+        }
+
         // Include written English word if the feature is enabled and it is not included already.
         // Avoid including meta character suggestion twice, so check `term` is not equal to the
         // captured preceding characters
         if config.get_suggestion_include_english() && !typed_added && term != string.preceding() {
-            self.suggestions
-                .push(Rank::last_ranked(term.to_string(), 3));
+            self.suggestions.push(Rank::last_ranked(
+                term.to_string(),
+                3,
+                CandidateKind::English,
+            ));
         }
 
+        // Normalise the suggestions, which also removes the equivalent ones.
+        normalize_suggestions(&mut self.suggestions, config.get_normalization());
+
         // Sort the suggestions.
         self.suggestions.sort();
 
         let selection = self.get_prev_selection(&string, data, selections);
 
-        (self.suggestions.clone(), selection)
+        // The romanisation of the selected suggestion.
+        if config.get_romanization() {
+            if let Some(romanized) = romanized_candidate(&self.suggestions, selection) {
+                push_checked(&mut self.suggestions, romanized);
+            }
+        }
+
+        // Smart punctuation is applied on the returned suggestions only, so
+        // the selections are learned from the transliterations. The typed
+        // English word and the romanisation are kept as they are.
+        let mut suggestions = self.suggestions.clone();
+        if punctuation::is_enabled(config) {
+            for suggestion in suggestions.iter_mut().filter(|suggestion| {
+                !matches!(
+                    suggestion.kind(),
+                    CandidateKind::English | CandidateKind::Romanized
+                )
+            }) {
+                *suggestion.change_item() = punctuate(suggestion.to_string(), config);
+            }
+        }
+
+        (suggestions, selection)
     }
 
     /// Make suggestions from the given `splitted_string`. This will include dictionary and auto-correct suggestion.
@@ -213,7 +359,12 @@ impl PhoneticSuggestion {
             return;
         }
 
-        self.phonetic.convert_into(string.word(), &mut self.pbuffer);
+        transliterate_into(
+            &self.phonetic,
+            self.language,
+            string.word(),
+            &mut self.pbuffer,
+        );
 
         let phonetic = self.pbuffer.clone();
 
@@ -223,17 +374,44 @@ impl PhoneticSuggestion {
 
             // Auto Correct item.
             if let Some(correct) = self.search_corrected(string.word(), data) {
-                let corrected = self.phonetic.convert(correct);
+                let corrected = self.transliterate(correct);
                 // Treat it as the first priority.
-                suggestions.push(Rank::first_ranked(corrected));
+                suggestions.push(Rank::first_ranked(corrected, CandidateKind::AutoCorrect));
             }
 
-            self.include_from_dictionary(string.word(), &phonetic, &mut suggestions);
+            match self.language {
+                Language::Bengali => {
+                    self.include_from_dictionary(string.word(), &phonetic, &mut suggestions)
+                }
+                Language::Assamese => suggestions.extend(
+                    data.search_dictionary(&phonetic)
+                        .iter()
+                        .map(|word| Rank::new_suggestion(word.clone(), &phonetic)),
+                ),
+            }
             // Add the suggestions into the cache.
             self.cache.insert(string.word().to_string(), suggestions);
         }
 
-        let suffixed_suggestions = self.add_suffix_to_suggestions(string.word(), data);
+        let mut suffixed_suggestions = self.add_suffix_to_suggestions(string.word(), data);
+
+        // The Assamese dictionary is small, so the words of the Bengali
+        // dictionary are suggested in the Assamese spelling if it has none.
+        if self.language == Language::Assamese
+            && suffixed_suggestions
+                .iter()
+                .all(|s| s.kind() == CandidateKind::AutoCorrect)
+        {
+            let mut words = self
+                .dict
+                .suggest(&self.language.phonetic_input(string.word()));
+            words.sort();
+            suffixed_suggestions.extend(
+                words.iter().map(|word| {
+                    Rank::new_suggestion(self.language.phonetic_output(word), &phonetic)
+                }),
+            );
+        }
 
         // Middle Items: Dictionary suggestions
         for suggestion in suffixed_suggestions {
@@ -241,7 +419,10 @@ impl PhoneticSuggestion {
         }
 
         // Last Item: Phonetic
-        push_checked(&mut self.suggestions, Rank::last_ranked(phonetic, 2));
+        push_checked(
+            &mut self.suggestions,
+            Rank::last_ranked(phonetic, 2, CandidateKind::Phonetic),
+        );
     }
 
     pub(crate) fn get_prev_selection(
@@ -325,6 +506,17 @@ impl PhoneticSuggestion {
     }
 }
 
+/// Transliterate the phonetic `text` into the `output` by the rules of the `language`.
+fn transliterate_into(phonetic: &Parser, language: Language, text: &str, output: &mut String) {
+    match language {
+        Language::Bengali => phonetic.convert_into(text, output),
+        Language::Assamese => {
+            phonetic.convert_into(&language.phonetic_input(text), output);
+            *output = language.phonetic_output(output);
+        }
+    }
+}
+
 // Implement Default trait on PhoneticSuggestion, actually for testing convenience.
 impl Default for PhoneticSuggestion {
     fn default() -> Self {
@@ -337,10 +529,14 @@ mod tests {
     use ahash::RandomState;
     use std::collections::HashMap;
 
+    use std::sync::Arc;
+
     use super::PhoneticSuggestion;
+    use crate::calendar::{DateTime, FixedClock};
     use crate::config::get_phonetic_method_defaults;
     use crate::data::Data;
-    use crate::suggestion::Rank;
+    use crate::language::Language;
+    use crate::suggestion::{Candidate, CandidateKind, Rank};
     use crate::utility::SplittedString;
 
     #[test]
@@ -373,8 +569,41 @@ mod tests {
             ]
         );
 
+        // A standalone quotation mark is curved, the straight one is kept as English.
         suggestion.suggest("\"", &data, &mut selections, &config);
-        assert_eq!(suggestion.suggestions, ["\""]);
+        assert_eq!(suggestion.suggestions, ["“", "\""]);
+    }
+
+    #[test]
+    fn test_suggestion_case_insensitive() {
+        let mut suggestion = PhoneticSuggestion::default();
+        let mut selections = HashMap::with_hasher(RandomState::new());
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+
+        config.set_phonetic_case_insensitive(true);
+
+        // The lower cased reading is found in the dictionary.
+        suggestion.suggest("Tumi", &data, &mut selections, &config);
+        assert_eq!(
+            suggestion.suggestions,
+            [
+                Rank::Other("তুমি".to_owned(), 0, CandidateKind::Dictionary),
+                Rank::Other("তুম্বি".to_owned(), 20, CandidateKind::Dictionary),
+                Rank::Other("তুম্বী".to_owned(), 30, CandidateKind::Dictionary),
+                Rank::Last("টুমি".to_owned(), 2, CandidateKind::Phonetic),
+            ]
+        );
+
+        // Both of the readings are in the dictionary, the typed one goes first.
+        suggestion.suggest("TaKa", &data, &mut selections, &config);
+        let position = |word| suggestion.suggestions.iter().position(|s| *s == word);
+        assert_eq!(position("টাকা"), Some(0));
+        assert!(position("তাকা").is_some());
+
+        // The readings don't differ.
+        suggestion.suggest("Ami", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["আমি", "অমি"]);
     }
 
     #[test]
@@ -423,6 +652,163 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_suggestion_punctuation() {
+        let mut suggestion = PhoneticSuggestion::default();
+        let mut selections = HashMap::with_hasher(RandomState::new());
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        config.set_suggestion_include_english(true);
+        config.set_punctuation_ellipsis(true);
+        config.set_punctuation_double_dari(true);
+
+        let (suggestions, _) = suggestion.suggest("ami..", &data, &mut selections, &config);
+        assert_eq!(suggestions[..2], ["আমি॥", "অমি॥"]);
+        assert_eq!(suggestions.last().unwrap(), &"ami..");
+        // The selections are learned without the smart punctuation.
+        assert_eq!(suggestion.suggestions[0], "আমি।।");
+
+        let (suggestions, _) = suggestion.suggest("ami...", &data, &mut selections, &config);
+        assert_eq!(suggestions[0], "আমি…");
+
+        config.set_punctuation_spacing(true);
+        let (suggestions, _) = suggestion.suggest("ami,tumi", &data, &mut selections, &config);
+        assert_eq!(suggestions[0], "আমি, তুমি");
+    }
+
+    #[test]
+    fn test_suggestion_romanization() {
+        let mut suggestion = PhoneticSuggestion::default();
+        let mut selections = HashMap::with_hasher(RandomState::new());
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        config.set_suggestion_include_english(true);
+        config.set_punctuation_double_dari(true);
+        config.set_romanization(true);
+
+        let (suggestions, _) = suggestion.suggest("ami", &data, &mut selections, &config);
+        assert_eq!(suggestions, ["আমি", "অমি", "ami", "āmi"]);
+
+        // The romanisation which is the same as the typed English word isn't repeated.
+        selections.insert("ami".to_owned(), "অমি".to_owned());
+        let (suggestions, selection) = suggestion.suggest("ami", &data, &mut selections, &config);
+        assert_eq!(selection, 1);
+        assert_eq!(suggestions, ["আমি", "অমি", "ami"]);
+
+        // The romanisation follows the previous selection.
+        let (suggestions, _) = suggestion.suggest("kotha", &data, &mut selections, &config);
+        assert_eq!(suggestions.last().unwrap().to_string(), "kathā");
+        selections.insert("kotha".to_owned(), "কোঠা".to_owned());
+        let (suggestions, selection) = suggestion.suggest("kotha", &data, &mut selections, &config);
+        assert_eq!(suggestions[selection], "কোঠা");
+        assert_eq!(suggestions.last().unwrap().to_string(), "kōṭhā");
+
+        // Nothing is romanised if an Emoji is selected.
+        selections.insert("kotha".to_owned(), "🗣\u{FE0F}".to_owned());
+        let (suggestions, _) = suggestion.suggest("kotha", &data, &mut selections, &config);
+        assert_eq!(suggestions.last().unwrap().to_string(), "kotha");
+
+        // The smart punctuation isn't applied on the romanisation.
+        selections.clear();
+        let (suggestions, _) = suggestion.suggest("ami..", &data, &mut selections, &config);
+        assert_eq!(suggestions, ["আমি॥", "অমি॥", "ami..", "āmi.."]);
+
+        config.set_ansi_encoding(true);
+        let (suggestions, _) = suggestion.suggest("ami", &data, &mut selections, &config);
+        assert_eq!(suggestions, ["আমি", "অমি"]);
+    }
+
+    #[test]
+    fn test_suggestion_number() {
+        let mut suggestion = PhoneticSuggestion::default();
+        let mut selections = HashMap::with_hasher(RandomState::new());
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        config.set_suggestion_include_english(true);
+
+        suggestion.suggest("1250", &data, &mut selections, &config);
+        assert_eq!(
+            suggestion.suggestions,
+            [
+                Rank::last_ranked("১২৫০".to_owned(), 2, CandidateKind::Phonetic),
+                Rank::last_ranked("এক হাজার দুইশো পঞ্চাশ".to_owned(), 2, CandidateKind::Number),
+                Rank::last_ranked("এক হাজার দুইশো পঞ্চাশতম".to_owned(), 2, CandidateKind::Number),
+                Rank::last_ranked("1250".to_owned(), 3, CandidateKind::English),
+            ]
+        );
+
+        suggestion.suggest("12.5", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["১২.৫", "বারো দশমিক পাঁচ", "12.5"]);
+
+        config.set_number_formatting(true);
+        suggestion.suggest("1250", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions[..2], ["১২৫০", "১,২৫০"]);
+
+        suggestion.suggest("$1250000", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions[..2], ["৳১২৫০০০০", "৳১২,৫০,০০০"]);
+
+        suggestion.suggest("tk1250", &data, &mut selections, &config);
+        assert!(suggestion.suggestions.contains(&Rank::last_ranked(
+            "৳১,২৫০".to_owned(),
+            2,
+            CandidateKind::Number
+        )));
+
+        suggestion.suggest("12.5%", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["১২.৫%", "বারো দশমিক পাঁচ%", "12.5%"]);
+
+        config.set_number_words(false);
+        suggestion.suggest("12.5%", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["১২.৫%", "12.5%"]);
+    }
+
+    #[test]
+    fn test_suggestion_date_time() {
+        let mut suggestion = PhoneticSuggestion::default();
+        let mut selections = HashMap::with_hasher(RandomState::new());
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new();
+        config.set_clock(Arc::new(FixedClock(DateTime {
+            year: 2026,
+            month: 4,
+            day: 14,
+            hour: 9,
+            minute: 30,
+        })));
+
+        suggestion.suggest("today", &data, &mut selections, &config);
+        assert_eq!(
+            suggestion.suggestions[1..],
+            [
+                "১৪ এপ্রিল ২০২৬",
+                "মঙ্গলবার, ১৪ এপ্রিল ২০২৬",
+                "১ বৈশাখ ১৪৩৩ বঙ্গাব্দ",
+                "১৪/০৪/২০২৬"
+            ]
+        );
+        assert_eq!(
+            Candidate::from(&suggestion.suggestions[1]).kind,
+            CandidateKind::DateTime
+        );
+
+        suggestion.suggest("(somoy)", &data, &mut selections, &config);
+        assert!(suggestion.suggestions.ends_with(&[
+            Rank::last_ranked("(সকাল ৯:৩০)".to_owned(), 2, CandidateKind::DateTime),
+            Rank::last_ranked("(০৯:৩০)".to_owned(), 2, CandidateKind::DateTime),
+        ]));
+
+        config.set_date_triggers(vec!["Din".to_owned()]);
+        suggestion.suggest("today", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions.len(), 1);
+        // The triggers are matched case-insensitively.
+        suggestion.suggest("din", &data, &mut selections, &config);
+        assert!(suggestion.suggestions.contains(&Rank::last_ranked(
+            "১৪/০৪/২০২৬".to_owned(),
+            2,
+            CandidateKind::DateTime
+        )));
+    }
+
     #[test]
     fn test_suggestion_only_phonetic() {
         let mut suggestion = PhoneticSuggestion::default();
@@ -506,6 +892,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assamese() {
+        let mut suggestion = PhoneticSuggestion::default();
+        let mut selections = HashMap::with_hasher(RandomState::new());
+        let mut config = get_phonetic_method_defaults();
+        let data = Data::new_with_language(Language::Assamese);
+        config.set_language(Language::Assamese);
+        suggestion.set_language(Language::Assamese);
+
+        suggestion.suggest("ghor", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["ঘৰ"]);
+
+        suggestion.suggest("xomaj", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["সমাজ"]);
+
+        suggestion.suggest("suwali", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["ছোৱালী", "সুৱালি"]);
+
+        suggestion.suggest("guwahati", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["গুৱাহাটী", "গুৱাহাতি"]);
+
+        suggestion.suggest("ghorbor", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["ঘৰবোৰ", "ঘৰবৰ"]);
+
+        suggestion.suggest("manuh", &data, &mut selections, &config);
+        suggestion.suggest("manuhk", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["মানুহক"]);
+        assert_eq!(suggestion.suggestions[0].kind(), CandidateKind::Suffixed);
+
+        assert_eq!(suggestion.suggest_only_phonetic("kotha"), "কথা");
+        assert_eq!(suggestion.suggest_only_phonetic("rati"), "ৰাতি");
+
+        // Words missing from the Assamese dictionary are taken from the Bengali one.
+        suggestion.suggest("rajniti", &data, &mut selections, &config);
+        assert_eq!(suggestion.suggestions, ["ৰাজনীতি", "ৰাজনিতি"]);
+    }
+
     #[test]
     fn test_suffix_suggestion() {
         let mut suggestion = PhoneticSuggestion::default();
@@ -601,16 +1024,31 @@ mod tests {
 
         cache.insert(
             "computer".to_string(),
-            vec![Rank::first_ranked("কম্পিউটার".to_string())],
+            vec![Rank::first_ranked(
+                "কম্পিউটার".to_string(),
+                CandidateKind::AutoCorrect,
+            )],
+        );
+        cache.insert(
+            "i".to_string(),
+            vec![Rank::first_ranked(
+                "ই".to_string(),
+                CandidateKind::AutoCorrect,
+            )],
         );
-        cache.insert("i".to_string(), vec![Rank::first_ranked("ই".to_string())]);
         cache.insert(
             "hothat".to_string(),
-            vec![Rank::first_ranked("হঠাৎ".to_string())],
+            vec![Rank::first_ranked(
+                "হঠাৎ".to_string(),
+                CandidateKind::AutoCorrect,
+            )],
         );
         cache.insert(
             "ebong".to_string(),
-            vec![Rank::first_ranked("এবং".to_string())],
+            vec![Rank::first_ranked(
+                "এবং".to_string(),
+                CandidateKind::AutoCorrect,
+            )],
         );
 
         let mut suggestion = PhoneticSuggestion {
@@ -660,8 +1098,8 @@ mod tests {
 
         // Avoid meta characters
         suggestion.suggestions = vec![
-            Rank::Other("*অন্ন?!".to_string(), 0),
-            Rank::Other("*অন্য?!".to_string(), 0),
+            Rank::Other("*অন্ন?!".to_string(), 0, CandidateKind::Dictionary),
+            Rank::Other("*অন্য?!".to_string(), 0, CandidateKind::Dictionary),
         ];
         assert_eq!(
             suggestion.get_prev_selection(
@@ -674,8 +1112,8 @@ mod tests {
 
         // With Suffix
         suggestion.suggestions = vec![
-            Rank::Other("ইএই".to_string(), 1),
-            Rank::Other("ইয়েই".to_string(), 2),
+            Rank::Other("ইএই".to_string(), 1, CandidateKind::Dictionary),
+            Rank::Other("ইয়েই".to_string(), 2, CandidateKind::Dictionary),
         ];
         assert_eq!(
             suggestion.get_prev_selection(
@@ -687,9 +1125,9 @@ mod tests {
         );
 
         suggestion.suggestions = vec![
-            Rank::Other("হোথাতে".to_string(), 0),
-            Rank::Other("হথাতে".to_string(), 0),
-            Rank::Other("হঠাতে".to_string(), 0),
+            Rank::Other("হোথাতে".to_string(), 0, CandidateKind::Dictionary),
+            Rank::Other("হথাতে".to_string(), 0, CandidateKind::Dictionary),
+            Rank::Other("হঠাতে".to_string(), 0, CandidateKind::Dictionary),
         ];
         assert_eq!(
             suggestion.get_prev_selection(
@@ -701,8 +1139,8 @@ mod tests {
         );
 
         suggestion.suggestions = vec![
-            Rank::Other("এবংমালা".to_string(), 0),
-            Rank::Other("এবঙমালা".to_string(), 0),
+            Rank::Other("এবংমালা".to_string(), 0, CandidateKind::Dictionary),
+            Rank::Other("এবঙমালা".to_string(), 0, CandidateKind::Dictionary),
         ];
         assert_eq!(
             suggestion.get_prev_selection(
@@ -715,8 +1153,8 @@ mod tests {
 
         // With Suffix + Avoid meta characters
         suggestion.suggestions = vec![
-            Rank::Other("*অন্নগুলো?!".to_string(), 0),
-            Rank::Other("*অন্যগুলো?!".to_string(), 0),
+            Rank::Other("*অন্নগুলো?!".to_string(), 0, CandidateKind::Dictionary),
+            Rank::Other("*অন্যগুলো?!".to_string(), 0, CandidateKind::Dictionary),
         ];
         assert_eq!(
             suggestion.get_prev_selection(
@@ -0,0 +1,272 @@
+// Per-application configuration profiles.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Config, ConfigError};
+use crate::encoding::Encoding;
+use crate::language::Language;
+use crate::normalization::Normalization;
+use crate::store::{UserStore, PROFILES};
+
+/// A named set of [`Config`] overrides.
+///
+/// Every option which is `None` is left as it is in the [`Config`] the
+/// profile is applied on. The layout and the data directories can't be
+/// overridden, so switching profiles never requires rebuilding the method.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_english: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_emoji: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phonetic_suggestion: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phonetic_case_insensitive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_suggestion: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_automatic_vowel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_automatic_chandra: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_traditional_kar: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_old_reph: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_numpad: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_old_kar_order: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<Encoding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smart_quote: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smart_quote_bengali: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_formatting: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_words: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalization: Option<Normalization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub romanization: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub punctuation_ellipsis: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub punctuation_dash: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub punctuation_dari: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub punctuation_double_dari: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub punctuation_spacing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub english_toggle_key: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub english_word_list: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_triggers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_triggers: Option<Vec<String>>,
+}
+
+impl Profile {
+    /// Parse the profile from its JSON representation, the same as an entry
+    /// of the saved profiles.
+    pub fn from_json(json: &str) -> Result<Profile, ConfigError> {
+        serde_json::from_str(json).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Applies the overrides of the profile on the `config`.
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(option) = self.language {
+            config.set_language(option);
+        }
+        if let Some(option) = self.include_english {
+            config.set_suggestion_include_english(option);
+        }
+        if let Some(option) = self.include_emoji {
+            config.set_suggestion_include_emoji(option);
+        }
+        if let Some(option) = self.phonetic_suggestion {
+            config.set_phonetic_suggestion(option);
+        }
+        if let Some(option) = self.phonetic_case_insensitive {
+            config.set_phonetic_case_insensitive(option);
+        }
+        if let Some(option) = self.fixed_suggestion {
+            config.set_fixed_suggestion(option);
+        }
+        if let Some(option) = self.fixed_automatic_vowel {
+            config.set_fixed_automatic_vowel(option);
+        }
+        if let Some(option) = self.fixed_automatic_chandra {
+            config.set_fixed_automatic_chandra(option);
+        }
+        if let Some(option) = self.fixed_traditional_kar {
+            config.set_fixed_traditional_kar(option);
+        }
+        if let Some(option) = self.fixed_old_reph {
+            config.set_fixed_old_reph(option);
+        }
+        if let Some(option) = self.fixed_numpad {
+            config.set_fixed_numpad(option);
+        }
+        if let Some(option) = self.fixed_old_kar_order {
+            config.set_fixed_old_kar_order(option);
+        }
+        if let Some(option) = self.encoding {
+            config.set_encoding(option);
+        }
+        if let Some(option) = self.smart_quote {
+            config.set_smart_quote(option);
+        }
+        if let Some(option) = self.smart_quote_bengali {
+            config.set_smart_quote_bengali(option);
+        }
+        if let Some(option) = self.number_formatting {
+            config.set_number_formatting(option);
+        }
+        if let Some(option) = self.number_words {
+            config.set_number_words(option);
+        }
+        if let Some(option) = self.normalization {
+            config.set_normalization(option);
+        }
+        if let Some(option) = self.romanization {
+            config.set_romanization(option);
+        }
+        if let Some(option) = self.punctuation_ellipsis {
+            config.set_punctuation_ellipsis(option);
+        }
+        if let Some(option) = self.punctuation_dash {
+            config.set_punctuation_dash(option);
+        }
+        if let Some(option) = self.punctuation_dari {
+            config.set_punctuation_dari(option);
+        }
+        if let Some(option) = self.punctuation_double_dari {
+            config.set_punctuation_double_dari(option);
+        }
+        if let Some(option) = self.punctuation_spacing {
+            config.set_punctuation_spacing(option);
+        }
+        if let Some(option) = self.page_size {
+            config.set_page_size(option);
+        }
+        if let Some(option) = self.english_toggle_key {
+            config.set_english_toggle_key(option);
+        }
+        if let Some(path) = &self.english_word_list {
+            // A missing word list leaves the one of the config in effect.
+            config.set_english_word_list(&path.to_string_lossy());
+        }
+        if let Some(option) = &self.date_triggers {
+            config.set_date_triggers(option.clone());
+        }
+        if let Some(option) = &self.time_triggers {
+            config.set_time_triggers(option.clone());
+        }
+    }
+}
+
+/// Profiles keyed by the application identifier.
+pub(crate) type Profiles = HashMap<String, Profile>;
+
+/// Load the profiles from the user `store`.
+pub(crate) fn load_profiles(store: &dyn UserStore) -> Profiles {
+    store
+        .read(PROFILES)
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Save the `profiles` into the user `store`.
+///
+/// The profiles are still in effect for the session if they couldn't be saved.
+pub(crate) fn save_profiles(store: &dyn UserStore, profiles: &Profiles) {
+    let _ = store.write(
+        PROFILES,
+        serde_json::to_string(profiles).unwrap().as_bytes(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_profiles, save_profiles, Profile, Profiles};
+    use crate::config::get_phonetic_method_defaults;
+    use crate::encoding::Encoding;
+    use crate::language::Language;
+    use crate::store::MemoryStore;
+
+    #[test]
+    fn test_profile_apply() {
+        let mut config = get_phonetic_method_defaults();
+        config.set_suggestion_include_english(true);
+
+        let profile = Profile {
+            include_emoji: Some(false),
+            encoding: Some(Encoding::Iscii),
+            language: Some(Language::Assamese),
+            page_size: Some(5),
+            punctuation_dari: Some(true),
+            english_word_list: Some("/nonexistent".into()),
+            time_triggers: Some(vec!["somoy".to_owned()]),
+            ..Default::default()
+        };
+        profile.apply(&mut config);
+
+        assert!(!config.get_suggestion_include_emoji());
+        assert_eq!(config.get_encoding(), Encoding::Iscii);
+        assert_eq!(config.get_language(), Language::Assamese);
+        assert_eq!(config.get_page_size(), 5);
+        assert!(config.get_punctuation_dari());
+        assert_eq!(config.get_time_triggers(), ["somoy"]);
+        // Not overridden.
+        assert!(config.get_phonetic_suggestion());
+        config.set_encoding(Encoding::Unicode);
+        assert!(config.get_suggestion_include_english());
+        assert_eq!(
+            config.get_english_word_list(),
+            get_phonetic_method_defaults().get_english_word_list()
+        );
+    }
+
+    #[test]
+    fn test_profile_json() {
+        let profile = Profile::from_json(r#"{"encoding": "bijoy", "page_size": 9}"#).unwrap();
+        assert_eq!(profile.encoding, Some(Encoding::Bijoy));
+        assert_eq!(profile.page_size, Some(9));
+        assert_eq!(profile.include_emoji, None);
+
+        assert!(Profile::from_json(r#"{"ansi_encoding": true}"#).is_err());
+        assert!(Profile::from_json(r#"{"encoding": "ascii"}"#).is_err());
+    }
+
+    #[test]
+    fn test_profile_persistence() {
+        let store = MemoryStore::new();
+        assert!(load_profiles(&store).is_empty());
+
+        let mut profiles = Profiles::default();
+        profiles.insert(
+            "code".to_owned(),
+            Profile {
+                include_emoji: Some(false),
+                ..Default::default()
+            },
+        );
+        save_profiles(&store, &profiles);
+
+        assert_eq!(load_profiles(&store), profiles);
+    }
+}
@@ -0,0 +1,137 @@
+//! Smart punctuation.
+//!
+//! Every rule has its own [`Config`] option and is applied in this order:
+//! * Ellipsis: `...` and `।।।` become `…`.
+//! * Dash: `--` becomes `—`.
+//! * Dari: A sentence-final `.` after a Bengali word becomes `।`, and a `।`
+//!   between digits becomes the decimal point `.`.
+//! * Double dari: `।।` becomes `॥`.
+//! * Spacing: A space is put between a punctuation mark and the following
+//!   Bengali word typed with it, like `আমি,তুমি` as `আমি, তুমি`.
+
+use crate::config::Config;
+
+/// Punctuation marks which are followed by a space by the spacing rule.
+const MARKS: &str = "।॥,;?!";
+
+/// Characters which can close a sentence after its final `.`.
+const CLOSINGS: &str = "'\"’”)]}";
+
+/// Applies the enabled smart punctuation rules of the `config` on the `text`.
+pub(crate) fn punctuate(text: &str, config: &Config) -> String {
+    let mut text = text.to_owned();
+
+    if config.get_punctuation_ellipsis() {
+        text = text.replace("...", "…").replace("।।।", "…");
+    }
+
+    if config.get_punctuation_dash() {
+        text = text.replace("--", "—");
+    }
+
+    if config.get_punctuation_dari() {
+        text = dari(&text);
+    }
+
+    if config.get_punctuation_double_dari() {
+        text = text.replace("।।", "॥");
+    }
+
+    if config.get_punctuation_spacing() {
+        text = spacing(&text);
+    }
+
+    text
+}
+
+/// Checks if any of the smart punctuation rules is enabled.
+pub(crate) fn is_enabled(config: &Config) -> bool {
+    config.get_punctuation_ellipsis()
+        || config.get_punctuation_dash()
+        || config.get_punctuation_dari()
+        || config.get_punctuation_double_dari()
+        || config.get_punctuation_spacing()
+}
+
+fn dari(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(index, &c)| {
+            let previous = index.checked_sub(1).map(|index| chars[index]);
+            let next = chars.get(index + 1).copied();
+
+            match c {
+                '.' if previous.is_some_and(is_bengali_letter)
+                    && next.map_or(true, |c| c.is_whitespace() || CLOSINGS.contains(c)) =>
+                {
+                    '।'
+                }
+                '।' if previous.is_some_and(is_digit) && next.is_some_and(is_digit) => '.',
+                _ => c,
+            }
+        })
+        .collect()
+}
+
+fn spacing(text: &str) -> String {
+    let mut spaced = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        spaced.push(c);
+
+        if MARKS.contains(c) && chars.peek().copied().is_some_and(is_bengali_letter) {
+            spaced.push(' ');
+        }
+    }
+
+    spaced
+}
+
+/// Checks if `c` is a Bengali letter or sign, but not a digit.
+fn is_bengali_letter(c: char) -> bool {
+    ('\u{0980}'..='\u{09FF}').contains(&c) && !is_digit(c)
+}
+
+/// Checks if `c` is an ASCII or Bengali digit.
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || ('০'..='৯').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::punctuate;
+    use crate::config::get_phonetic_method_defaults;
+
+    #[test]
+    fn test_punctuate() {
+        let mut config = get_phonetic_method_defaults();
+        assert_eq!(punctuate("আমি...", &config), "আমি...");
+
+        config.set_punctuation_ellipsis(true);
+        assert_eq!(punctuate("আমি...", &config), "আমি…");
+        assert_eq!(punctuate("আমি।।।", &config), "আমি…");
+
+        config.set_punctuation_dash(true);
+        assert_eq!(punctuate("আমি--তুমি", &config), "আমি—তুমি");
+
+        config.set_punctuation_dari(true);
+        assert_eq!(punctuate("আমি.", &config), "আমি।");
+        assert_eq!(punctuate("আমি.\"", &config), "আমি।\"");
+        assert_eq!(punctuate("ডা.মো", &config), "ডা.মো");
+        assert_eq!(punctuate("১২.", &config), "১২.");
+        assert_eq!(punctuate("১২।৫", &config), "১২.৫");
+
+        config.set_punctuation_double_dari(true);
+        assert_eq!(punctuate("আমি।।", &config), "আমি॥");
+
+        config.set_punctuation_spacing(true);
+        assert_eq!(punctuate("আমি,তুমি", &config), "আমি, তুমি");
+        assert_eq!(punctuate("আমি।।তুমি", &config), "আমি॥ তুমি");
+        assert_eq!(punctuate("আমি?", &config), "আমি?");
+        assert_eq!(punctuate("১২,৩৪৫", &config), "১২,৩৪৫");
+    }
+}
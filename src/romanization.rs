@@ -0,0 +1,271 @@
+//! Romanisation of the Bengali text.
+//!
+//! The text is transliterated into the Latin script by ISO 15919, which gives
+//! every letter its own Latin letter, with diacritics where needed, so that the
+//! romanisation can be read back unambiguously:
+//! * The inherent vowel of a consonant is written as `a`, unless the hasanta
+//!   or a kar takes its place, like `কথা` as `kathā` and `বাক্য` as `bākya`.
+//! * The anusvara, the visarga and the chandrabindu are written as `ṁ`, `ḥ`
+//!   and `m̐`, like `বাংলা` as `bāṁlā` and `চাঁদ` as `cām̐da`.
+//! * A colon separates the letters which would otherwise be read as one, like
+//!   `কই` as `ka:i`, unlike `কৈ` as `kai`, and `ব্হ` as `b:ha`, unlike `ভ` as `bha`.
+
+use crate::normalization::{normalize, Normalization};
+use crate::suggestion::{CandidateKind, Rank};
+
+/// Consonants which make an aspirated consonant when followed by `h`.
+const UNASPIRATED: [&str; 11] = ["k", "g", "c", "j", "ṭ", "ḍ", "t", "d", "p", "b", "ṛ"];
+
+/// Makes the romanisation candidate of the `selection` among the `suggestions`.
+///
+/// Nothing is made for an Emoji or an English word, which aren't Bengali.
+pub(crate) fn romanized_candidate(suggestions: &[Rank], selection: usize) -> Option<Rank> {
+    suggestions
+        .get(selection)
+        .filter(|selected| {
+            !matches!(
+                selected.kind(),
+                CandidateKind::Emoji | CandidateKind::English
+            )
+        })
+        .map(|selected| {
+            Rank::last_ranked(romanize(selected.to_string()), 3, CandidateKind::Romanized)
+        })
+}
+
+/// Romanises the Bengali `text` by ISO 15919.
+///
+/// The characters other than the Bengali ones are kept as they are.
+pub fn romanize(text: &str) -> String {
+    let text = normalize(text, Normalization::Bangla);
+    let mut output = String::with_capacity(text.len() * 2);
+    // The consonant whose inherent vowel isn't written yet.
+    let mut pending: Option<&str> = None;
+    // The consonant which is joined with the next one by the hasanta.
+    let mut joined: Option<&str> = None;
+    // Whether the last written letter is the vowel `a`.
+    let mut after_a = false;
+
+    for c in text.chars() {
+        match c {
+            // ZWNJ, ZWJ and the nukta don't change the romanisation.
+            '\u{200C}' | '\u{200D}' | '\u{09BC}' => continue,
+            '\u{09CD}' => {
+                joined = pending.take();
+                continue;
+            }
+            _ => (),
+        }
+
+        if let Some(vowel) = kar(c) {
+            pending = None;
+            output.push_str(vowel);
+            after_a = false;
+            joined = None;
+            continue;
+        }
+
+        // The inherent vowel.
+        if pending.take().is_some() {
+            output.push('a');
+            after_a = true;
+        }
+
+        if let Some(latin) = consonant(c) {
+            if latin == "h" && joined.is_some_and(|joined| UNASPIRATED.contains(&joined)) {
+                output.push(':');
+            }
+            output.push_str(latin);
+            // The khanda ta has no inherent vowel.
+            pending = (c != 'ৎ').then_some(latin);
+            after_a = false;
+        } else if let Some(latin) = vowel(c) {
+            if after_a && matches!(c, 'ই' | 'উ') {
+                output.push(':');
+            }
+            output.push_str(latin);
+            after_a = c == 'অ';
+        } else {
+            match sign(c) {
+                Some(latin) => output.push_str(latin),
+                None => output.push(c),
+            }
+            after_a = false;
+        }
+        joined = None;
+    }
+
+    if pending.is_some() {
+        output.push('a');
+    }
+
+    output
+}
+
+/// Latin letters of the consonant `c`.
+fn consonant(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'ক' => "k",
+        'খ' => "kh",
+        'গ' => "g",
+        'ঘ' => "gh",
+        'ঙ' => "ṅ",
+        'চ' => "c",
+        'ছ' => "ch",
+        'জ' => "j",
+        'ঝ' => "jh",
+        'ঞ' => "ñ",
+        'ট' => "ṭ",
+        'ঠ' => "ṭh",
+        'ড' => "ḍ",
+        'ঢ' => "ḍh",
+        'ণ' => "ṇ",
+        'ত' | 'ৎ' => "t",
+        'থ' => "th",
+        'দ' => "d",
+        'ধ' => "dh",
+        'ন' => "n",
+        'প' => "p",
+        'ফ' => "ph",
+        'ব' => "b",
+        'ভ' => "bh",
+        'ম' => "m",
+        'য' => "y",
+        'র' | 'ৰ' => "r",
+        'ল' => "l",
+        'শ' => "ś",
+        'ষ' => "ṣ",
+        'স' => "s",
+        'হ' => "h",
+        '\u{09DC}' => "ṛ",
+        '\u{09DD}' => "ṛh",
+        '\u{09DF}' => "ẏ",
+        'ৱ' => "v",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+/// Latin letters of the independent vowel `c`.
+fn vowel(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'অ' => "a",
+        'আ' => "ā",
+        'ই' => "i",
+        'ঈ' => "ī",
+        'উ' => "u",
+        'ঊ' => "ū",
+        'ঋ' => "r̥",
+        'ৠ' => "r̥̄",
+        'ঌ' => "l̥",
+        'ৡ' => "l̥̄",
+        'এ' => "ē",
+        'ঐ' => "ai",
+        'ও' => "ō",
+        'ঔ' => "au",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+/// Latin letters of the kar `c`.
+fn kar(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'া' => "ā",
+        'ি' => "i",
+        'ী' => "ī",
+        'ু' => "u",
+        'ূ' => "ū",
+        'ৃ' => "r̥",
+        'ৄ' => "r̥̄",
+        'ৢ' => "l̥",
+        'ৣ' => "l̥̄",
+        'ে' => "ē",
+        'ৈ' => "ai",
+        'ো' => "ō",
+        'ৌ' | 'ৗ' => "au",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+/// Latin letters of the sign, the digit or the punctuation mark `c`.
+fn sign(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'ং' => "ṁ",
+        'ঃ' => "ḥ",
+        'ঁ' => "m̐",
+        'ঽ' => "’",
+        '।' => ".",
+        '॥' => "..",
+        '০' => "0",
+        '১' => "1",
+        '২' => "2",
+        '৩' => "3",
+        '৪' => "4",
+        '৫' => "5",
+        '৬' => "6",
+        '৭' => "7",
+        '৮' => "8",
+        '৯' => "9",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::romanize;
+
+    #[test]
+    fn test_romanize() {
+        // Letters of the ISO 15919 tables.
+        assert_eq!(
+            romanize("অ আ ই ঈ উ ঊ ঋ ৠ ঌ ৡ এ ঐ ও ঔ"),
+            "a ā i ī u ū r̥ r̥̄ l̥ l̥̄ ē ai ō au"
+        );
+        assert_eq!(
+            romanize("ক খ গ ঘ ঙ চ ছ জ ঝ ঞ ট ঠ ড ঢ ণ ত থ দ ধ ন"),
+            "ka kha ga gha ṅa ca cha ja jha ña ṭa ṭha ḍa ḍha ṇa ta tha da dha na"
+        );
+        assert_eq!(
+            romanize("প ফ ব ভ ম য র ল শ ষ স হ \u{09DC} \u{09DD} \u{09DF} ৎ"),
+            "pa pha ba bha ma ya ra la śa ṣa sa ha ṛa ṛha ẏa t"
+        );
+        assert_eq!(
+            romanize("কা কি কী কু কূ কৃ কে কৈ কো কৌ ক্"),
+            "kā ki kī ku kū kr̥ kē kai kō kau k"
+        );
+
+        // The inherent vowel and the conjuncts.
+        assert_eq!(romanize("কথা"), "kathā");
+        assert_eq!(romanize("বাক্য"), "bākya");
+        assert_eq!(romanize("ক্ষমা"), "kṣamā");
+        assert_eq!(romanize("বিজ্ঞান"), "bijñāna");
+        assert_eq!(romanize("রবীন্দ্রনাথ ঠাকুর"), "rabīndranātha ṭhākura");
+        assert_eq!(romanize("কর্ম"), "karma");
+        assert_eq!(romanize("র\u{200D}্যাব"), "ryāba");
+        assert_eq!(romanize("উৎসব"), "utsaba");
+
+        // The signs.
+        assert_eq!(romanize("বাংলা"), "bāṁlā");
+        assert_eq!(romanize("দুঃখ"), "duḥkha");
+        assert_eq!(romanize("চাঁদ"), "cām̐da");
+        assert_eq!(romanize("পা\u{09A1}\u{09BC}া"), "pāṛā");
+
+        // The ambiguous letters.
+        assert_eq!(romanize("কই ঐ"), "ka:i ai");
+        assert_eq!(romanize("অউ ঔ"), "a:u au");
+        assert_eq!(romanize("অদ্ভুত"), "adbhuta");
+        assert_eq!(romanize("ব্হ"), "b:ha");
+
+        // Assamese letters, digits and the punctuation marks.
+        assert_eq!(romanize("ঘৰ ৱ"), "ghara va");
+        assert_eq!(romanize("আমি ১২৩ টাকা দিলাম।"), "āmi 123 ṭākā dilāma.");
+        assert_eq!(romanize("Hello!"), "Hello!");
+    }
+}
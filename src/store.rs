@@ -0,0 +1,150 @@
+// User data storage backends.
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs::{metadata, read, write},
+    io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+/// Name of the user's phonetic candidate selection entries.
+pub const PHONETIC_SELECTIONS: &str = "phonetic-candidate-selection.json";
+/// Name of the user defined Auto Correct entries.
+pub const PHONETIC_AUTOCORRECT: &str = "autocorrect.json";
+/// Name of the per-application configuration profiles.
+pub const PROFILES: &str = "profiles.json";
+
+/// Storage of the user specific data of the engine.
+///
+/// All reads and writes of user data (candidate selections, user defined
+/// Auto Correct entries, etc.) go through this trait, so the host can keep
+/// the data wherever it wants: on the disk, in memory or in its own storage.
+///
+/// Entries are identified by their `name`, for example [`PHONETIC_SELECTIONS`].
+pub trait UserStore: Debug + Send + Sync {
+    /// Returns the content of the entry `name`, if it exists.
+    fn read(&self, name: &str) -> Option<Vec<u8>>;
+
+    /// Replaces the content of the entry `name` with `data`.
+    fn write(&self, name: &str, data: &[u8]) -> io::Result<()>;
+
+    /// Returns the time of the last modification of the entry `name`, if it exists.
+    ///
+    /// It is used to reload an entry only when it was changed in the meantime.
+    fn modified(&self, name: &str) -> Option<SystemTime>;
+}
+
+/// A [`UserStore`] which keeps the entries as files in a directory.
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    /// Creates a new `FileStore` which keeps the entries in the `dir` directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FileStore { dir: dir.into() }
+    }
+
+    /// Returns the directory of the store.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl UserStore for FileStore {
+    fn read(&self, name: &str) -> Option<Vec<u8>> {
+        read(self.dir.join(name)).ok()
+    }
+
+    fn write(&self, name: &str, data: &[u8]) -> io::Result<()> {
+        write(self.dir.join(name), data)
+    }
+
+    fn modified(&self, name: &str) -> Option<SystemTime> {
+        metadata(self.dir.join(name))
+            .and_then(|m| m.modified())
+            .ok()
+    }
+}
+
+/// A [`UserStore`] which keeps the entries in memory.
+///
+/// Nothing is persisted, the entries are lost when the store is dropped.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, (Vec<u8>, SystemTime)>>,
+}
+
+impl MemoryStore {
+    /// Creates a new empty `MemoryStore`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl UserStore for MemoryStore {
+    fn read(&self, name: &str) -> Option<Vec<u8>> {
+        self.entries
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|(data, _)| data.clone())
+    }
+
+    fn write(&self, name: &str, data: &[u8]) -> io::Result<()> {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(name.to_owned(), (data.to_vec(), SystemTime::now()));
+        Ok(())
+    }
+
+    fn modified(&self, name: &str) -> Option<SystemTime> {
+        self.entries
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|(_, modified)| *modified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FileStore, MemoryStore, UserStore};
+
+    #[test]
+    fn test_memory_store() {
+        let store = MemoryStore::new();
+
+        assert_eq!(store.read("entry"), None);
+        assert_eq!(store.modified("entry"), None);
+
+        store.write("entry", b"data").unwrap();
+        assert_eq!(store.read("entry"), Some(b"data".to_vec()));
+
+        let modified = store.modified("entry").unwrap();
+        store.write("entry", b"new data").unwrap();
+        assert_eq!(store.read("entry"), Some(b"new data".to_vec()));
+        assert!(store.modified("entry").unwrap() >= modified);
+    }
+
+    #[test]
+    fn test_file_store() {
+        let dir = std::env::temp_dir().join(format!("riti-store-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = FileStore::new(&dir);
+
+        assert_eq!(store.read("entry.json"), None);
+        assert_eq!(store.modified("entry.json"), None);
+
+        store.write("entry.json", b"{}").unwrap();
+        assert_eq!(store.read("entry.json"), Some(b"{}".to_vec()));
+        assert!(store.modified("entry.json").is_some());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,24 +1,41 @@
 use edit_distance::edit_distance;
-use poriborton::bijoy2000::unicode_to_bijoy;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
+use crate::encoding::Encoding;
+
 /// Suggestions which are intended to be shown by the IM's candidate window.
 /// Suggestion is of two variants, the 'Full' one includes a list of suggestion and
 /// the 'Single' one is just a String.
-#[derive(Debug)]
+///
+/// With the `serde` feature, it is serialized as a JSON object tagged with
+/// `"type": "full"` or `"type": "single"`. The format is described by the
+/// JSON schema in `schema/suggestion.schema.json`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "json::Suggestion", try_from = "json::Suggestion")
+)]
 pub enum Suggestion {
     Full {
         auxiliary: String,
-        suggestions: Vec<String>,
-        // Index of the last selected suggestion.
+        // The suggestions along with their metadata.
+        candidates: Vec<Candidate>,
+        // Index of the last selected suggestion, relative to the page.
         selection: usize,
-        // ANSI output
-        ansi: bool,
+        // Index of the current page.
+        page: usize,
+        // Number of suggestions in all of the pages.
+        total: usize,
+        // Encoding of the output
+        encoding: Encoding,
     },
     Single {
         suggestion: String,
-        // ANSI output
-        ansi: bool,
+        // Encoding of the output
+        encoding: Encoding,
     },
 }
 
@@ -31,16 +48,20 @@ impl Suggestion {
     ///
     /// `selection`: Index of the last selected suggestion.
     ///
-    /// `ansi`: Enable ANSI encoding conversion.
-    pub fn new(auxiliary: String, suggestions: &[Rank], selection: usize, ansi: bool) -> Self {
+    /// `encoding`: Encoding of the output.
+    pub fn new(
+        auxiliary: String,
+        suggestions: &[Rank],
+        selection: usize,
+        encoding: Encoding,
+    ) -> Self {
         Self::Full {
             auxiliary,
-            suggestions: suggestions
-                .iter()
-                .map(|r| r.to_string().to_owned())
-                .collect(),
+            candidates: suggestions.iter().map(Candidate::from).collect(),
             selection,
-            ansi,
+            page: 0,
+            total: suggestions.len(),
+            encoding,
         }
     }
 
@@ -50,16 +71,19 @@ impl Suggestion {
     ///
     /// `suggestion`: The suggestion.
     ///
-    /// `ansi`: Enable ANSI encoding conversion.
-    pub fn new_lonely(suggestion: String, ansi: bool) -> Self {
-        Self::Single { suggestion, ansi }
+    /// `encoding`: Encoding of the output.
+    pub fn new_lonely(suggestion: String, encoding: Encoding) -> Self {
+        Self::Single {
+            suggestion,
+            encoding,
+        }
     }
 
     /// Constructs an empty `Suggestion` struct.
     pub fn empty() -> Self {
         Self::Single {
             suggestion: String::new(),
-            ansi: false,
+            encoding: Encoding::Unicode,
         }
     }
 
@@ -73,100 +97,300 @@ impl Suggestion {
     /// Returns `true` if the `Suggestion` struct is empty.
     pub fn is_empty(&self) -> bool {
         match &self {
-            Self::Full { suggestions, .. } => suggestions.is_empty(),
+            Self::Full { candidates, .. } => candidates.is_empty(),
             Self::Single { suggestion, .. } => suggestion.is_empty(),
         }
     }
 
-    /// Get the suggestions as an iterator.
-    pub fn get_suggestions(&self) -> &[String] {
+    /// Get the suggestions.
+    ///
+    /// Returns `None` if the `Suggestion` is a *lonely* one.
+    pub fn get_suggestions(&self) -> Option<Vec<&str>> {
         match &self {
-            Self::Full { suggestions, .. } => suggestions,
-            _ => panic!(),
+            Self::Full { candidates, .. } => Some(
+                candidates
+                    .iter()
+                    .map(|candidate| candidate.text.as_str())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Get the suggestions along with their metadata.
+    ///
+    /// Returns `None` if the `Suggestion` is a *lonely* one.
+    pub fn get_candidates(&self) -> Option<&[Candidate]> {
+        match &self {
+            Self::Full { candidates, .. } => Some(candidates),
+            _ => None,
         }
     }
 
     /// Get the only suggestion of the *lonely* `Suggestion`.
-    pub fn get_lonely_suggestion(&self) -> &str {
+    ///
+    /// Returns `None` if the `Suggestion` is not a *lonely* one.
+    pub fn get_lonely_suggestion(&self) -> Option<&str> {
         match &self {
-            Self::Single { suggestion, .. } => suggestion,
-            _ => panic!(),
+            Self::Single { suggestion, .. } => Some(suggestion),
+            _ => None,
         }
     }
 
     /// Get the auxiliary text.
-    pub fn get_auxiliary_text(&self) -> &str {
+    ///
+    /// Returns `None` if the `Suggestion` is a *lonely* one.
+    pub fn get_auxiliary_text(&self) -> Option<&str> {
         match &self {
-            Self::Full { auxiliary, .. } => auxiliary,
-            _ => panic!(),
+            Self::Full { auxiliary, .. } => Some(auxiliary),
+            _ => None,
         }
     }
 
     /// Get the pre-edit text from the list of the `index'.
     ///
     /// This returns the lone suggestion if the suggestion is a lonely one.
+    /// Returns `None` if the `index` is out of range.
     ///
     /// The main purpose of the function is to convert the returning suggestion into
-    /// the ANSI encoding if it was specified when the instance of this `Suggestion`
+    /// the encoding which was specified when the instance of this `Suggestion`
     /// was created.
-    pub fn get_pre_edit_text(&self, index: usize) -> String {
-        match self {
+    pub fn get_pre_edit_text(&self, index: usize) -> Option<String> {
+        let (text, encoding) = match self {
             Self::Full {
-                suggestions, ansi, ..
-            } if *ansi => unicode_to_bijoy(&suggestions[index]),
-            Self::Full { suggestions, .. } => suggestions[index].to_owned(),
-            Self::Single { suggestion, ansi } if *ansi => unicode_to_bijoy(suggestion),
-            Self::Single { suggestion, .. } => suggestion.clone(),
-        }
+                candidates,
+                encoding,
+                ..
+            } => (&candidates.get(index)?.text, encoding),
+            Self::Single {
+                suggestion,
+                encoding,
+            } => (suggestion, encoding),
+        };
+
+        Some(encoding.encode(text).text)
     }
 
     /// Returns index of the suggestion, which was previously selected.
-    pub fn previously_selected_index(&self) -> usize {
+    /// The index is relative to the current page.
+    ///
+    /// Returns `None` if the `Suggestion` is a *lonely* one.
+    pub fn previously_selected_index(&self) -> Option<usize> {
+        match &self {
+            Self::Full { selection, .. } => Some(*selection),
+            _ => None,
+        }
+    }
+
+    /// Returns index of the current page of the suggestions.
+    ///
+    /// Returns `None` if the `Suggestion` is a *lonely* one.
+    pub fn current_page(&self) -> Option<usize> {
         match &self {
-            Self::Full { selection, .. } => *selection,
-            _ => panic!(),
+            Self::Full { page, .. } => Some(*page),
+            _ => None,
         }
     }
 
+    /// Get the number of suggestions in all of the pages.
+    pub fn total_len(&self) -> usize {
+        match &self {
+            Self::Full { total, .. } => *total,
+            _ => self.len(),
+        }
+    }
+
+    /// Returns the `page` of the suggestions which has `page_size` suggestions.
+    ///
+    /// The `page` is limited to the last page. The previously selected
+    /// suggestion is kept selected if it is in the page, otherwise the first
+    /// suggestion of the page is selected.
+    pub(crate) fn page(&self, page: usize, page_size: usize) -> Self {
+        match self {
+            Self::Full {
+                auxiliary,
+                candidates,
+                selection,
+                encoding,
+                ..
+            } => {
+                let page = page.min(candidates.len().saturating_sub(1) / page_size);
+                let range = page * page_size..candidates.len().min((page + 1) * page_size);
+                let selection = if range.contains(selection) {
+                    selection - range.start
+                } else {
+                    0
+                };
+
+                Self::Full {
+                    auxiliary: auxiliary.clone(),
+                    candidates: candidates[range].to_vec(),
+                    selection,
+                    page,
+                    total: candidates.len(),
+                    encoding: *encoding,
+                }
+            }
+            Self::Single {
+                suggestion,
+                encoding,
+            } => Self::Single {
+                suggestion: suggestion.clone(),
+                encoding: *encoding,
+            },
+        }
+    }
+
+    /// Serializes the `Suggestion` into JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Deserializes a `Suggestion` from JSON.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
     /// Get the length of the suggestions contained.
+    ///
+    /// A non-empty *lonely* `Suggestion` contains one suggestion.
     pub fn len(&self) -> usize {
         match &self {
-            Self::Full { suggestions, .. } => suggestions.len(),
-            _ => panic!(),
+            Self::Full { candidates, .. } => candidates.len(),
+            Self::Single { suggestion, .. } => usize::from(!suggestion.is_empty()),
+        }
+    }
+}
+
+/// Source of a suggestion.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CandidateKind {
+    /// The text as the user typed it.
+    Typed,
+    /// An Auto Correct entry.
+    AutoCorrect,
+    /// A dictionary word.
+    Dictionary,
+    /// A dictionary word with a suffix added.
+    Suffixed,
+    /// An Emoji.
+    Emoji,
+    /// The typed English word.
+    English,
+    /// Phonetic transliteration of the typed text.
+    Phonetic,
+    /// The typed number spelled in words.
+    Number,
+    /// The current date or time.
+    DateTime,
+    /// ISO 15919 romanisation of a suggestion.
+    Romanized,
+}
+
+impl CandidateKind {
+    /// Returns a short description of the source, if it is worth showing to the user.
+    fn annotation(self) -> Option<&'static str> {
+        match self {
+            CandidateKind::AutoCorrect => Some("Auto Correct"),
+            CandidateKind::English => Some("English"),
+            CandidateKind::Romanized => Some("ISO 15919"),
+            _ => None,
+        }
+    }
+}
+
+/// Group of a suggestion in the ranking, the variant of its [`Rank`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RankTier {
+    /// Shown before everything else.
+    First,
+    /// An Emoji, shown before the other suggestions.
+    Emoji,
+    /// The usual suggestions.
+    #[default]
+    Other,
+    /// Shown after everything else.
+    Last,
+}
+
+/// A suggestion along with its metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Candidate {
+    /// The suggestion.
+    pub text: String,
+    /// Source of the suggestion.
+    pub kind: CandidateKind,
+    /// Score of the suggestion among the suggestions of its group,
+    /// lower is better. Dictionary words are scored by their edit distance.
+    pub score: u8,
+    /// Group of the suggestion in the ranking.
+    pub tier: RankTier,
+    /// An optional note to show alongside the suggestion.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub annotation: Option<String>,
+}
+
+impl From<&Rank> for Candidate {
+    fn from(rank: &Rank) -> Self {
+        let (tier, score, kind) = match *rank {
+            Rank::First(_, kind) => (RankTier::First, 0, kind),
+            Rank::Emoji(_, score) => (RankTier::Emoji, score, CandidateKind::Emoji),
+            Rank::Other(_, score, kind) => (RankTier::Other, score, kind),
+            Rank::Last(_, score, kind) => (RankTier::Last, score, kind),
+        };
+
+        Candidate {
+            text: rank.to_string().to_owned(),
+            kind,
+            score,
+            tier,
+            annotation: kind.annotation().map(str::to_owned),
         }
     }
 }
 
 #[derive(Clone, Debug)]
 pub enum Rank {
-    First(String),
+    First(String, CandidateKind),
     Emoji(String, u8),
-    Other(String, u8),
-    Last(String, u8),
+    Other(String, u8, CandidateKind),
+    Last(String, u8, CandidateKind),
 }
 
 impl Rank {
     /// Returns the suggestion item.
     pub(crate) fn to_string(&self) -> &str {
         match self {
-            Rank::First(s) => s,
+            Rank::First(s, _) => s,
             Rank::Emoji(s, _) => s,
-            Rank::Other(s, _) => s,
-            Rank::Last(s, _) => s,
+            Rank::Other(s, _, _) => s,
+            Rank::Last(s, _, _) => s,
         }
     }
 
     /// A first ranked suggestion.
-    pub(crate) fn first_ranked(item: String) -> Self {
-        Rank::First(item)
+    pub(crate) fn first_ranked(item: String, kind: CandidateKind) -> Self {
+        Rank::First(item, kind)
     }
 
-    /// A suggestion with a ranking calculated according to the `base` word.
+    /// A dictionary suggestion with a ranking calculated according to the `base` word.
     ///
     /// Uses edit distance to rank the `item`.
     pub(crate) fn new_suggestion(item: String, base: &str) -> Self {
         let distance = edit_distance(base, &item) * 10;
-        Rank::Other(item, distance as u8)
+        Rank::Other(item, distance as u8, CandidateKind::Dictionary)
     }
 
     /// An Emoji suggestion.
@@ -180,54 +404,65 @@ impl Rank {
     }
 
     /// A suggestion with a low `rank` ranking.
-    pub(crate) fn last_ranked(item: String, rank: u8) -> Self {
-        Rank::Last(item, rank)
+    pub(crate) fn last_ranked(item: String, rank: u8, kind: CandidateKind) -> Self {
+        Rank::Last(item, rank, kind)
     }
 
     /// Gives a mutable reference of the Rank's item.
     pub(crate) fn change_item(&mut self) -> &mut String {
         match self {
-            Rank::First(s) => s,
+            Rank::First(s, _) => s,
             Rank::Emoji(s, _) => s,
-            Rank::Other(s, _) => s,
-            Rank::Last(s, _) => s,
+            Rank::Other(s, _, _) => s,
+            Rank::Last(s, _, _) => s,
+        }
+    }
+
+    /// Returns the source of the suggestion.
+    pub(crate) fn kind(&self) -> CandidateKind {
+        match *self {
+            Rank::First(_, kind) | Rank::Other(_, _, kind) | Rank::Last(_, _, kind) => kind,
+            Rank::Emoji(_, _) => CandidateKind::Emoji,
+        }
+    }
+
+    /// Changes the source of the suggestion. Emojis are always kept as they are.
+    pub(crate) fn set_kind(&mut self, new: CandidateKind) {
+        match self {
+            Rank::First(_, kind) | Rank::Other(_, _, kind) | Rank::Last(_, _, kind) => *kind = new,
+            Rank::Emoji(_, _) => (),
         }
     }
 }
 
 impl PartialEq<&str> for Rank {
     fn eq(&self, other: &&str) -> bool {
-        match self {
-            Rank::First(s) => s == other,
-            Rank::Emoji(s, _) => s == other,
-            Rank::Other(s, _) => s == other,
-            Rank::Last(s, _) => s == other,
-        }
+        self.to_string() == *other
     }
 }
 
 impl Ord for Rank {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Rank::First(_), Rank::First(_)) => Ordering::Equal,
-            (Rank::First(_), Rank::Emoji(_, _)) => Ordering::Less,
-            (Rank::Emoji(_, _), Rank::First(_)) => Ordering::Greater,
-            (Rank::First(_), Rank::Other(_, _)) => Ordering::Less,
-            (Rank::Other(_, _), Rank::First(_)) => Ordering::Greater,
-            (Rank::First(_), Rank::Last(_, _)) => Ordering::Less,
-            (Rank::Last(_, _), Rank::First(_)) => Ordering::Greater,
-
-            (Rank::Emoji(_, _), Rank::Emoji(_, _)) => Ordering::Equal,
-            (Rank::Emoji(_, e), Rank::Other(_, s)) => e.cmp(s),
-            (Rank::Other(_, s), Rank::Emoji(_, e)) => s.cmp(e),
-            (Rank::Emoji(_, _), Rank::Last(_, _)) => Ordering::Less,
-            (Rank::Last(_, _), Rank::Emoji(_, _)) => Ordering::Greater,
-
-            (Rank::Other(_, s1), Rank::Other(_, s2)) => s1.cmp(s2),
-            (Rank::Other(_, _), Rank::Last(_, _)) => Ordering::Less,
-            (Rank::Last(_, _), Rank::Other(_, _)) => Ordering::Greater,
-
-            (Rank::Last(_, s1), Rank::Last(_, s2)) => s1.cmp(s2),
+            (Rank::First(..), Rank::First(..)) => Ordering::Equal,
+            (Rank::First(..), Rank::Emoji(..)) => Ordering::Less,
+            (Rank::Emoji(..), Rank::First(..)) => Ordering::Greater,
+            (Rank::First(..), Rank::Other(..)) => Ordering::Less,
+            (Rank::Other(..), Rank::First(..)) => Ordering::Greater,
+            (Rank::First(..), Rank::Last(..)) => Ordering::Less,
+            (Rank::Last(..), Rank::First(..)) => Ordering::Greater,
+
+            (Rank::Emoji(..), Rank::Emoji(..)) => Ordering::Equal,
+            (Rank::Emoji(_, e), Rank::Other(_, s, _)) => e.cmp(s),
+            (Rank::Other(_, s, _), Rank::Emoji(_, e)) => s.cmp(e),
+            (Rank::Emoji(..), Rank::Last(..)) => Ordering::Less,
+            (Rank::Last(..), Rank::Emoji(..)) => Ordering::Greater,
+
+            (Rank::Other(_, s1, _), Rank::Other(_, s2, _)) => s1.cmp(s2),
+            (Rank::Other(..), Rank::Last(..)) => Ordering::Less,
+            (Rank::Last(..), Rank::Other(..)) => Ordering::Greater,
+
+            (Rank::Last(_, s1, _), Rank::Last(_, s2, _)) => s1.cmp(s2),
         }
     }
 }
@@ -246,6 +481,107 @@ impl PartialEq for Rank {
 
 impl Eq for Rank {}
 
+/// The JSON form of the [`Suggestion`](super::Suggestion).
+#[cfg(feature = "serde")]
+mod json {
+    use serde::{Deserialize, Serialize};
+
+    use super::Candidate;
+    use crate::encoding::Encoding;
+
+    /// Version of `schema/suggestion.schema.json`.
+    pub(super) const SCHEMA_VERSION: u32 = 1;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub(super) enum Suggestion {
+        Full {
+            schema_version: u32,
+            auxiliary: String,
+            candidates: Vec<Candidate>,
+            selection: usize,
+            page: usize,
+            total: usize,
+            encoding: Encoding,
+        },
+        Single {
+            schema_version: u32,
+            suggestion: String,
+            encoding: Encoding,
+        },
+    }
+
+    impl From<super::Suggestion> for Suggestion {
+        fn from(suggestion: super::Suggestion) -> Self {
+            match suggestion {
+                super::Suggestion::Full {
+                    auxiliary,
+                    candidates,
+                    selection,
+                    page,
+                    total,
+                    encoding,
+                } => Suggestion::Full {
+                    schema_version: SCHEMA_VERSION,
+                    auxiliary,
+                    candidates,
+                    selection,
+                    page,
+                    total,
+                    encoding,
+                },
+                super::Suggestion::Single {
+                    suggestion,
+                    encoding,
+                } => Suggestion::Single {
+                    schema_version: SCHEMA_VERSION,
+                    suggestion,
+                    encoding,
+                },
+            }
+        }
+    }
+
+    impl TryFrom<Suggestion> for super::Suggestion {
+        type Error = String;
+
+        fn try_from(suggestion: Suggestion) -> Result<Self, Self::Error> {
+            match suggestion {
+                Suggestion::Full { schema_version, .. }
+                | Suggestion::Single { schema_version, .. }
+                    if schema_version != SCHEMA_VERSION =>
+                {
+                    Err(format!("unsupported schema version {schema_version}"))
+                }
+                Suggestion::Full {
+                    auxiliary,
+                    candidates,
+                    selection,
+                    page,
+                    total,
+                    encoding,
+                    ..
+                } => Ok(super::Suggestion::Full {
+                    auxiliary,
+                    candidates,
+                    selection,
+                    page,
+                    total,
+                    encoding,
+                }),
+                Suggestion::Single {
+                    suggestion,
+                    encoding,
+                    ..
+                } => Ok(super::Suggestion::Single {
+                    suggestion,
+                    encoding,
+                }),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,25 +590,199 @@ mod tests {
     fn test_ansi_encoding() {
         let suggestion = Suggestion::new(
             "test".to_owned(),
-            &[Rank::first_ranked("হাই".to_owned())],
+            &[Rank::first_ranked("হাই".to_owned(), CandidateKind::Typed)],
             0,
-            true,
+            Encoding::Bijoy,
         );
-        assert_eq!(suggestion.get_pre_edit_text(0), "nvB");
+        assert_eq!(suggestion.get_pre_edit_text(0).unwrap(), "nvB");
 
         let suggestion = Suggestion::new(
             "test".to_owned(),
-            &[Rank::first_ranked("হাই".to_owned())],
+            &[Rank::first_ranked("হাই".to_owned(), CandidateKind::Typed)],
             0,
-            false,
+            Encoding::Unicode,
+        );
+        assert_eq!(suggestion.get_pre_edit_text(0).unwrap(), "হাই");
+
+        let suggestion = Suggestion::new_lonely("হাই".to_owned(), Encoding::Bijoy);
+        assert_eq!(suggestion.get_pre_edit_text(0).unwrap(), "nvB");
+
+        let suggestion = Suggestion::new_lonely("হাই".to_owned(), Encoding::Iscii);
+        assert_eq!(
+            suggestion.get_pre_edit_text(0).unwrap(),
+            "\u{D8}\u{DA}\u{A6}"
+        );
+
+        let suggestion = Suggestion::new_lonely("হাই".to_owned(), Encoding::Unicode);
+        assert_eq!(suggestion.get_pre_edit_text(0).unwrap(), "হাই");
+    }
+
+    #[test]
+    fn test_accessors() {
+        let suggestion = Suggestion::new(
+            "test".to_owned(),
+            &[Rank::first_ranked("হাই".to_owned(), CandidateKind::Typed)],
+            0,
+            Encoding::Unicode,
+        );
+        assert_eq!(suggestion.get_suggestions().unwrap(), ["হাই"]);
+        assert_eq!(suggestion.get_lonely_suggestion(), None);
+        assert_eq!(suggestion.get_auxiliary_text(), Some("test"));
+        assert_eq!(suggestion.get_pre_edit_text(1), None);
+        assert_eq!(suggestion.previously_selected_index(), Some(0));
+        assert_eq!(suggestion.len(), 1);
+
+        let suggestion = Suggestion::new_lonely("হাই".to_owned(), Encoding::Unicode);
+        assert_eq!(suggestion.get_suggestions(), None);
+        assert_eq!(suggestion.get_candidates(), None);
+        assert_eq!(suggestion.get_lonely_suggestion(), Some("হাই"));
+        assert_eq!(suggestion.get_auxiliary_text(), None);
+        assert_eq!(suggestion.previously_selected_index(), None);
+        assert_eq!(suggestion.len(), 1);
+        assert_eq!(Suggestion::empty().len(), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json() {
+        let suggestion = Suggestion::new(
+            "ami".to_owned(),
+            &[
+                Rank::first_ranked("আমি".to_owned(), CandidateKind::AutoCorrect),
+                Rank::last_ranked("ami".to_owned(), 3, CandidateKind::English),
+            ],
+            1,
+            Encoding::Unicode,
         );
-        assert_eq!(suggestion.get_pre_edit_text(0), "হাই");
+        let json = suggestion.to_json();
+        assert_eq!(
+            json,
+            r#"{"type":"full","schema_version":1,"auxiliary":"ami","candidates":[{"text":"আমি","kind":"auto_correct","score":0,"tier":"first","annotation":"Auto Correct"},{"text":"ami","kind":"english","score":3,"tier":"last","annotation":"English"}],"selection":1,"page":0,"total":2,"encoding":"unicode"}"#
+        );
+        let decoded = Suggestion::from_json(&json).unwrap();
+        assert_eq!(decoded.get_candidates(), suggestion.get_candidates());
+        assert_eq!(decoded.previously_selected_index(), Some(1));
+
+        let suggestion = Suggestion::new_lonely("আমি".to_owned(), Encoding::Bijoy);
+        let json = suggestion.to_json();
+        assert_eq!(
+            json,
+            r#"{"type":"single","schema_version":1,"suggestion":"আমি","encoding":"bijoy"}"#
+        );
+        let decoded = Suggestion::from_json(&json).unwrap();
+        assert_eq!(decoded.get_lonely_suggestion(), Some("আমি"));
+        assert_eq!(decoded.get_pre_edit_text(0).unwrap(), "Avwg");
+
+        // The documents of other versions of the schema aren't read.
+        assert!(Suggestion::from_json(
+            r#"{"type":"single","schema_version":2,"suggestion":"আমি","encoding":"bijoy"}"#
+        )
+        .is_err());
+        assert!(Suggestion::from_json(
+            r#"{"type":"single","suggestion":"আমি","encoding":"bijoy"}"#
+        )
+        .is_err());
+    }
 
-        let suggestion = Suggestion::new_lonely("হাই".to_owned(), true);
-        assert_eq!(suggestion.get_pre_edit_text(0), "nvB");
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_schema() {
+        let schema: serde_json::Value =
+            serde_json::from_str(include_str!("../schema/suggestion.schema.json")).unwrap();
+        let definitions = &schema["$defs"];
+
+        // Every serialized field is described in the schema.
+        let full: serde_json::Value = serde_json::from_str(
+            &Suggestion::new(
+                "a".to_owned(),
+                &[Rank::first_ranked("আ".to_owned(), CandidateKind::Typed)],
+                0,
+                Encoding::Unicode,
+            )
+            .to_json(),
+        )
+        .unwrap();
+        let single: serde_json::Value = serde_json::from_str(
+            &Suggestion::new_lonely("আ".to_owned(), Encoding::Unicode).to_json(),
+        )
+        .unwrap();
+
+        for (value, definition) in [
+            (&full, &definitions["full"]),
+            (&single, &definitions["single"]),
+            (&full["candidates"][0], &definitions["candidate"]),
+        ] {
+            for key in value.as_object().unwrap().keys() {
+                assert!(
+                    definition["properties"].get(key).is_some(),
+                    "`{key}` is missing in the schema"
+                );
+            }
+            for key in definition["required"].as_array().unwrap() {
+                assert!(value.get(key.as_str().unwrap()).is_some());
+            }
+        }
 
-        let suggestion = Suggestion::new_lonely("হাই".to_owned(), false);
-        assert_eq!(suggestion.get_pre_edit_text(0), "হাই");
+        assert_eq!(
+            schema["$id"],
+            format!("urn:riti:suggestion:{}", super::json::SCHEMA_VERSION)
+        );
+        assert_eq!(full["schema_version"], super::json::SCHEMA_VERSION);
+        assert_eq!(single["schema_version"], super::json::SCHEMA_VERSION);
+
+        let kinds = definitions["kind"]["enum"].as_array().unwrap();
+        for kind in [
+            CandidateKind::Typed,
+            CandidateKind::AutoCorrect,
+            CandidateKind::Dictionary,
+            CandidateKind::Suffixed,
+            CandidateKind::Emoji,
+            CandidateKind::English,
+            CandidateKind::Phonetic,
+            CandidateKind::Number,
+            CandidateKind::DateTime,
+            CandidateKind::Romanized,
+        ] {
+            assert!(kinds.contains(&serde_json::to_value(kind).unwrap()));
+        }
+
+        let encodings = definitions["encoding"]["enum"].as_array().unwrap();
+        for encoding in [Encoding::Unicode, Encoding::Bijoy, Encoding::Iscii] {
+            assert!(encodings.contains(&serde_json::to_value(encoding).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_pages() {
+        let ranks: Vec<Rank> = ["ক", "খ", "গ", "ঘ", "ঙ"]
+            .iter()
+            .map(|&s| Rank::new_suggestion(s.to_owned(), "ক"))
+            .collect();
+        let suggestion = Suggestion::new("k".to_owned(), &ranks, 3, Encoding::Unicode);
+        assert_eq!(suggestion.current_page(), Some(0));
+        assert_eq!(suggestion.total_len(), 5);
+
+        let page = suggestion.page(0, 2);
+        assert_eq!(page.get_suggestions().unwrap(), ["ক", "খ"]);
+        assert_eq!(page.get_candidates().unwrap().len(), 2);
+        assert_eq!(page.previously_selected_index(), Some(0));
+        assert_eq!(page.current_page(), Some(0));
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.total_len(), 5);
+
+        let page = suggestion.page(1, 2);
+        assert_eq!(page.get_suggestions().unwrap(), ["গ", "ঘ"]);
+        assert_eq!(page.previously_selected_index(), Some(1));
+        assert_eq!(page.get_auxiliary_text(), Some("k"));
+
+        // Limited to the last page.
+        let page = suggestion.page(5, 2);
+        assert_eq!(page.get_suggestions().unwrap(), ["ঙ"]);
+        assert_eq!(page.current_page(), Some(2));
+
+        let lonely = Suggestion::new_lonely("ক".to_owned(), Encoding::Unicode);
+        assert_eq!(lonely.current_page(), None);
+        assert_eq!(lonely.total_len(), 1);
     }
 
     #[test]
@@ -281,23 +791,23 @@ mod tests {
         assert_eq!(r, "Happy");
 
         let mut vr1 = vec![
-            Rank::Last(":)".to_owned(), 2),
-            Rank::Last("Thanks!".to_owned(), 1),
-            Rank::Other("my".to_owned(), 10),
-            Rank::Other("friend!".to_owned(), 20),
-            Rank::First("Hello".to_owned()),
+            Rank::Last(":)".to_owned(), 2, CandidateKind::Typed),
+            Rank::Last("Thanks!".to_owned(), 1, CandidateKind::English),
+            Rank::Other("my".to_owned(), 10, CandidateKind::Dictionary),
+            Rank::Other("friend!".to_owned(), 20, CandidateKind::Dictionary),
+            Rank::First("Hello".to_owned(), CandidateKind::AutoCorrect),
             Rank::Emoji("✋".to_owned(), 1),
         ];
         vr1.sort_unstable();
         assert_eq!(
             vr1,
             vec![
-                Rank::First("Hello".to_owned()),
+                Rank::First("Hello".to_owned(), CandidateKind::AutoCorrect),
                 Rank::Emoji("✋".to_owned(), 1),
-                Rank::Other("my".to_owned(), 10),
-                Rank::Other("friend!".to_owned(), 20),
-                Rank::Last("Thanks!".to_owned(), 1),
-                Rank::Last(":)".to_owned(), 2)
+                Rank::Other("my".to_owned(), 10, CandidateKind::Dictionary),
+                Rank::Other("friend!".to_owned(), 20, CandidateKind::Dictionary),
+                Rank::Last("Thanks!".to_owned(), 1, CandidateKind::English),
+                Rank::Last(":)".to_owned(), 2, CandidateKind::Typed)
             ]
         );
         assert_eq!(vr1, ["Hello", "✋", "my", "friend!", "Thanks!", ":)"]);
@@ -321,4 +831,45 @@ mod tests {
         suggestion.sort_unstable();
         assert_eq!(suggestion, ["আ", "🅰️", "আঃ", "া", "এ", "অ্যা", "অ্যাঁ"]);
     }
+
+    #[test]
+    fn test_candidates() {
+        let suggestion = Suggestion::new(
+            "hi".to_owned(),
+            &[
+                Rank::first_ranked("হাই".to_owned(), CandidateKind::AutoCorrect),
+                Rank::emoji_ranked("👋".to_owned(), 2),
+                Rank::new_suggestion("হি".to_owned(), "হই"),
+                Rank::last_ranked("hi".to_owned(), 3, CandidateKind::English),
+            ],
+            0,
+            Encoding::Unicode,
+        );
+        let candidates = suggestion.get_candidates().unwrap();
+        assert_eq!(
+            candidates[0],
+            Candidate {
+                text: "হাই".to_owned(),
+                kind: CandidateKind::AutoCorrect,
+                score: 0,
+                tier: RankTier::First,
+                annotation: Some("Auto Correct".to_owned()),
+            }
+        );
+        assert_eq!(candidates[1].kind, CandidateKind::Emoji);
+        assert_eq!(candidates[1].tier, RankTier::Emoji);
+        assert_eq!(candidates[1].score, 2);
+        assert_eq!(candidates[2].kind, CandidateKind::Dictionary);
+        assert_eq!(candidates[2].score, 10);
+        assert_eq!(candidates[2].annotation, None);
+        assert_eq!(candidates[3].kind, CandidateKind::English);
+        assert_eq!(candidates[3].tier, RankTier::Last);
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|c| c.text.as_str())
+                .collect::<Vec<_>>(),
+            suggestion.get_suggestions().unwrap()
+        );
+    }
 }
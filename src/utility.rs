@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fs::File, io::Read, ops::Deref};
+use std::{borrow::Cow, ops::Deref};
 
 use crate::context::{MODIFIER_ALT_GR, MODIFIER_SHIFT};
 
@@ -26,7 +26,7 @@ impl Utility for char {
 
     /// Checks the char for a pure consonant character.
     fn is_pure_consonant(&self) -> bool {
-        "\u{0995}\u{0996}\u{0997}\u{0998}\u{0999}\u{099A}\u{099B}\u{099C}\u{099D}\u{099E}\u{099F}\u{09A0}\u{09A1}\u{09A2}\u{09A3}\u{09A4}\u{09A5}\u{09A6}\u{09A7}\u{09A8}\u{09AA}\u{09AB}\u{09AC}\u{09AD}\u{09AE}\u{09AF}\u{09B0}\u{09B2}\u{09B6}\u{09B7}\u{09B8}\u{09B9}\u{09CE}\u{09DC}\u{09DD}\u{09DF}".contains(*self)
+        "\u{0995}\u{0996}\u{0997}\u{0998}\u{0999}\u{099A}\u{099B}\u{099C}\u{099D}\u{099E}\u{099F}\u{09A0}\u{09A1}\u{09A2}\u{09A3}\u{09A4}\u{09A5}\u{09A6}\u{09A7}\u{09A8}\u{09AA}\u{09AB}\u{09AC}\u{09AD}\u{09AE}\u{09AF}\u{09B0}\u{09B2}\u{09B6}\u{09B7}\u{09B8}\u{09B9}\u{09CE}\u{09DC}\u{09DD}\u{09DF}\u{09F0}\u{09F1}".contains(*self)
     }
 }
 
@@ -52,20 +52,13 @@ pub(crate) fn get_modifiers(modifier: u8) -> Modifiers {
     (shift, alt_gr)
 }
 
-/// Read the entire contents of a file into a bytes vector.
-///
-/// Optimized to allocate the required amount of capacity beforehand.
-pub(crate) fn read(file: &mut File) -> Vec<u8> {
-    let len = file.metadata().map(|m| m.len() + 1).unwrap();
-    let mut buf = Vec::with_capacity(len as usize);
-    file.read_to_end(&mut buf).unwrap();
-    buf
-}
-
 /// A meta characters splitted string.
 ///
 /// Meta characters (`-]~!@#%&*()_=+[{}'\";<>/?|.,।`) are splitted
 /// from a string as preceding and trailing parts.
+///
+/// The text of the URL, Email and password fields is never splitted, as it's
+/// passed through as a whole, so their meta characters stay in the text.
 #[derive(Debug)]
 pub(crate) struct SplittedString<'a> {
     preceding: Cow<'a, str>,
@@ -153,38 +146,103 @@ impl PartialEq<(&str, &str, &str)> for SplittedString<'_> {
     }
 }
 
+/// Open quotations of the committed text.
+///
+/// It's used to pair a standalone quotation mark with the open quotation.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Quotes {
+    double: bool,
+    single: bool,
+}
+
+impl Quotes {
+    /// Update the open quotations with the curved quotation marks of the `committed` text.
+    pub(crate) fn update(&mut self, committed: &str) {
+        for c in committed.chars() {
+            self.push(c);
+        }
+    }
+
+    fn push(&mut self, mark: char) {
+        match mark {
+            '“' => self.double = true,
+            '”' => self.double = false,
+            '‘' => self.single = true,
+            '’' => self.single = false,
+            _ => (),
+        }
+    }
+
+    /// Checks if the quotation of the `opening` mark is open.
+    fn is_open(&self, opening: char) -> bool {
+        if opening == '“' {
+            self.double
+        } else {
+            self.single
+        }
+    }
+}
+
+/// Returns the opening and closing curved forms of the quotation mark `c`.
+///
+/// The Bengali style uses the single quotation marks(‘, ’) for the double
+/// quotation mark and the double ones(“, ”) for the single quotation mark.
+fn curved_quotes(c: char, bengali: bool) -> Option<(char, char)> {
+    match (c, bengali) {
+        ('"', false) | ('\'', true) => Some(('“', '”')),
+        ('\'', false) | ('"', true) => Some(('‘', '’')),
+        _ => None,
+    }
+}
+
 /// Convert preceding and trailing quotation marks(', ") into their curved form(‘, ’, “, ”) aka Smart Quote.
-pub(crate) fn smart_quoter(mut splitted: SplittedString) -> SplittedString {
-    // If the middle part is empty, there is no need to convert.
+///
+/// Standalone quotation marks close the open quotations of the `quotes` and open the others.
+/// Optionally the quotation marks are converted in the Bengali style.
+pub(crate) fn smart_quoter(
+    mut splitted: SplittedString,
+    quotes: Quotes,
+    bengali: bool,
+) -> SplittedString {
+    // Pair the standalone quotation marks with the open quotations.
     if splitted.word().is_empty() {
+        let mut quotes = quotes;
+        let preceding = splitted
+            .preceding()
+            .chars()
+            .map(|ch| match curved_quotes(ch, bengali) {
+                Some((opening, closing)) => {
+                    let mark = if quotes.is_open(opening) {
+                        closing
+                    } else {
+                        opening
+                    };
+                    quotes.push(mark);
+                    mark
+                }
+                None => ch,
+            })
+            .collect();
+
+        splitted.preceding = Cow::Owned(preceding);
         return splitted;
     }
 
     // Convert preceding quotation mark(', ") into its curved form(‘, “).
     let mut preceding = String::with_capacity(splitted.preceding().len() + 3);
     for ch in splitted.preceding().chars() {
-        match ch {
-            '\'' => {
-                preceding.push('‘');
-            }
-            '"' => {
-                preceding.push('“');
-            }
-            _ => preceding.push(ch),
+        match curved_quotes(ch, bengali) {
+            Some((opening, _)) => preceding.push(opening),
+            None => preceding.push(ch),
         }
     }
 
     // Convert trailing quotation mark(', ") into its curved form(’, ”).
     let mut trailing = String::with_capacity(splitted.trailing().len() + 3);
     for ch in splitted.trailing.chars() {
-        match ch {
-            '\'' => {
-                trailing.push('’');
-            }
-            '"' => {
-                trailing.push('”');
-            }
-            _ => trailing.push(ch),
+        match curved_quotes(ch, bengali) {
+            Some((_, closing)) => trailing.push(closing),
+            None => trailing.push(ch),
         }
     }
 
@@ -204,7 +262,7 @@ pub(crate) fn clean_string(string: &str) -> String {
 
 #[cfg(test)]
 mod test {
-    use super::{get_modifiers, smart_quoter, SplittedString, Utility};
+    use super::{get_modifiers, smart_quoter, Quotes, SplittedString, Utility};
     use crate::context::{MODIFIER_ALT_GR, MODIFIER_SHIFT};
 
     #[test]
@@ -214,6 +272,8 @@ mod test {
         assert!('া'.is_kar());
         assert!(!'আ'.is_kar());
         assert!('ক'.is_pure_consonant());
+        assert!('ৰ'.is_pure_consonant());
+        assert!('ৱ'.is_pure_consonant());
     }
 
     #[test]
@@ -255,50 +315,93 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_smart_quoting() {
+        let mut quotes = Quotes::default();
+
+        // Standalone quotation marks
+        assert_eq!(
+            smart_quoter(SplittedString::split("\"", true), quotes, false),
+            ("“".into(), "", "".into())
+        );
         assert_eq!(
-            smart_quoter(SplittedString::split("\"", true)),
-            ("\"".into(), "", "".into())
+            smart_quoter(SplittedString::split("\"\"", true), quotes, false),
+            ("“”", "", "")
         );
 
         assert_eq!(
-            smart_quoter(SplittedString::split("'Till", true)),
+            smart_quoter(SplittedString::split("'Till", true), quotes, false),
             ("‘".into(), "Till", "".into())
         );
         assert_eq!(
-            smart_quoter(SplittedString::split("\"Hey", true)),
+            smart_quoter(SplittedString::split("\"Hey", true), quotes, false),
             ("“".into(), "Hey", "".into())
         );
         assert_eq!(
-            smart_quoter(SplittedString::split("'\"Hey", true)),
+            smart_quoter(SplittedString::split("'\"Hey", true), quotes, false),
             ("‘“".into(), "Hey", "".into())
         );
 
         assert_eq!(
-            smart_quoter(SplittedString::split("finished'", true)),
+            smart_quoter(SplittedString::split("finished'", true), quotes, false),
             ("".into(), "finished", "’".into())
         );
         assert_eq!(
-            smart_quoter(SplittedString::split("Hey\"", true)),
+            smart_quoter(SplittedString::split("Hey\"", true), quotes, false),
             ("".into(), "Hey", "”".into())
         );
         assert_eq!(
-            smart_quoter(SplittedString::split("Hey'\"", true)),
+            smart_quoter(SplittedString::split("Hey'\"", true), quotes, false),
             ("".into(), "Hey", "’”".into())
         );
 
         assert_eq!(
-            smart_quoter(SplittedString::split("'Awkward'", true)),
+            smart_quoter(SplittedString::split("'Awkward'", true), quotes, false),
             ("‘".into(), "Awkward", "’".into())
         );
         assert_eq!(
-            smart_quoter(SplittedString::split("\"Nevertheless\"", true)),
+            smart_quoter(
+                SplittedString::split("\"Nevertheless\"", true),
+                quotes,
+                false
+            ),
             ("“".into(), "Nevertheless", "”".into())
         );
 
         assert_eq!(
-            smart_quoter(SplittedString::split("\"'Quotation'\"", true)),
+            smart_quoter(
+                SplittedString::split("\"'Quotation'\"", true),
+                quotes,
+                false
+            ),
             ("“‘".into(), "Quotation", "’”".into())
         );
+
+        // Pairing with the committed quotations
+        quotes.update("“আমি");
+        assert_eq!(
+            smart_quoter(SplittedString::split("\"", true), quotes, false),
+            ("”", "", "")
+        );
+        assert_eq!(
+            smart_quoter(SplittedString::split(".'", true), quotes, false),
+            (".‘", "", "")
+        );
+        quotes.update("তুমি”");
+        assert_eq!(
+            smart_quoter(SplittedString::split("\"", true), quotes, false),
+            ("“", "", "")
+        );
+
+        // Bengali style
+        assert_eq!(
+            smart_quoter(SplittedString::split("\"Hey'", true), quotes, true),
+            ("‘", "Hey", "”")
+        );
+        quotes.update("‘আমি");
+        assert_eq!(
+            smart_quoter(SplittedString::split("\"", true), quotes, true),
+            ("’", "", "")
+        );
     }
 }
@@ -0,0 +1,116 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::json;
+
+fn riti(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_riti"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// Writes a settings file with its own user directory into a temporary
+/// directory, so the user data of the machine isn't read.
+fn settings(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("riti-cli-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = json!({
+        "layout": "avro_phonetic",
+        "database_dir": concat!(env!("CARGO_MANIFEST_DIR"), "/data"),
+        "user_dir": dir,
+    });
+    std::fs::write(dir.join("settings.json"), options.to_string()).unwrap();
+
+    dir
+}
+
+fn stdout(output: Output) -> String {
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_transliterate() {
+    let output = riti(&["transliterate"], "ami gan gai.\nbangla\n");
+    assert_eq!(stdout(output), "আমি গান গাই।\nবাংলা\n");
+
+    let dir = std::env::temp_dir().join(format!("riti-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("input.txt");
+    std::fs::write(&file, "tumi\n").unwrap();
+
+    let output = riti(&["transliterate", file.to_str().unwrap()], "");
+    assert_eq!(stdout(output), "তুমি\n");
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_suggest() {
+    let dir = settings("suggest");
+    let settings = dir.join("settings.json");
+    let settings = settings.to_str().unwrap();
+
+    let output = stdout(riti(&["suggest", "--config", settings, "ami"], ""));
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("1. আমি (Other, Dictionary, score 0)"));
+    assert_eq!(lines.next(), Some("2. আমই (Other, Suffixed, score 0)"));
+
+    let output = stdout(riti(&["suggest", "--config", settings, "academy"], ""));
+    assert!(output.starts_with("1. অ্যাকাডেমি (First, AutoCorrect, score 0) [Auto Correct]\n"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_layout_show() {
+    let layout = concat!(env!("CARGO_MANIFEST_DIR"), "/data/Probhat.json");
+    let output = stdout(riti(&["layout", "show", layout], ""));
+    assert!(output.contains("1\t১\t৴\n"));
+    assert!(output.contains("$\t৳\t৲\n"));
+
+    assert!(!riti(&["layout", "show", "/nonexistent.json"], "")
+        .status
+        .success());
+}
+
+#[test]
+fn test_autocorrect_lookup() {
+    let dir = settings("autocorrect");
+    let settings = dir.join("settings.json");
+    let settings = settings.to_str().unwrap();
+
+    let output = stdout(riti(
+        &["autocorrect", "lookup", "--config", settings, "academy"],
+        "",
+    ));
+    assert_eq!(output, "bundled\toZakaDemi\tঅ্যাকাডেমি\n");
+
+    assert!(!riti(
+        &["autocorrect", "lookup", "--config", settings, "\\nai\\"],
+        ""
+    )
+    .status
+    .success());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_usage() {
+    let output = riti(&["unknown"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("Usage"));
+}
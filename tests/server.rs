@@ -0,0 +1,206 @@
+#![cfg(feature = "serde")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use serde_json::{json, Value};
+
+struct Server {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    dir: PathBuf,
+}
+
+impl Server {
+    fn start(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("riti-server-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let settings = dir.join("settings.json");
+        let options = json!({
+            "layout": "avro_phonetic",
+            "database_dir": concat!(env!("CARGO_MANIFEST_DIR"), "/data"),
+            "user_dir": dir,
+            "phonetic_suggestion": true,
+        });
+        std::fs::write(&settings, options.to_string()).unwrap();
+
+        let mut child = Command::new(env!("CARGO_BIN_EXE_riti-server"))
+            .arg("--config")
+            .arg(&settings)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+
+        Server {
+            child,
+            stdin,
+            stdout,
+            dir,
+        }
+    }
+
+    fn send(&mut self, line: &str) -> Value {
+        writeln!(self.stdin, "{line}").unwrap();
+        let mut response = String::new();
+        self.stdout.read_line(&mut response).unwrap();
+        serde_json::from_str(&response).unwrap()
+    }
+
+    fn call(&mut self, method: &str, params: Value) -> Value {
+        let request = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params});
+        let response = self.send(&request.to_string());
+        assert_eq!(response["id"], 1);
+        response
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn test_input() {
+    let mut server = Server::start("input");
+
+    let response = server.call("key", json!({"key": riti::keycodes::VC_A}));
+    assert_eq!(response["result"]["type"], "full");
+    assert_eq!(response["result"]["auxiliary"], "a");
+
+    let response = server.call("text", json!({"text": "mi"}));
+    let suggestion = &response["result"];
+    assert_eq!(suggestion["auxiliary"], "ami");
+    assert_eq!(suggestion["candidates"][0]["text"], "আমি");
+    assert_eq!(suggestion["candidates"][0]["kind"], "dictionary");
+
+    let response = server.call("backspace", json!({}));
+    assert_eq!(response["result"]["auxiliary"], "am");
+
+    let response = server.call("commit", json!({"index": 0}));
+    assert_eq!(response["result"], Value::Null);
+
+    // Nothing to page.
+    let response = server.call("page_down", Value::Null);
+    assert_eq!(response["result"], Value::Null);
+
+    let response = server.call("transliterate", json!({"text": "ami gan gai."}));
+    assert_eq!(response["result"], "আমি গান গাই।");
+}
+
+#[test]
+fn test_config() {
+    let mut server = Server::start("config");
+
+    let response = server.call("config", json!({"phonetic_suggestion": false}));
+    assert_eq!(response["result"], Value::Null);
+    let response = server.call("text", json!({"text": "ami"}));
+    assert_eq!(response["result"]["type"], "single");
+    assert_eq!(response["result"]["suggestion"], "আমি");
+    server.call("finish", Value::Null);
+
+    let response = server.call(
+        "config",
+        json!({"phonetic_suggestion": true, "page_size": 1}),
+    );
+    assert_eq!(response["result"], Value::Null);
+    server.call("text", json!({"text": "ami"}));
+    let response = server.call("page_down", Value::Null);
+    assert_eq!(response["result"]["page"], 1);
+
+    let response = server.call("config", json!({"unknown_option": true}));
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[test]
+fn test_errors() {
+    let mut server = Server::start("errors");
+
+    let response = server.send("not json");
+    assert_eq!(response["error"]["code"], -32700);
+
+    let response = server.send(r#"{"id": 1, "method": "finish"}"#);
+    assert_eq!(response["error"]["code"], -32600);
+
+    // Invalid requests without an id are answered too.
+    for request in ["{}", "[1]"] {
+        let response = server.send(request);
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], -32600);
+    }
+
+    let response = server.call("unknown", Value::Null);
+    assert_eq!(response["error"]["code"], -32601);
+
+    let response = server.call("key", json!({"key": "a"}));
+    assert_eq!(response["error"]["code"], -32602);
+
+    let response = server.call("text", json!({"text": "a b"}));
+    assert_eq!(response["error"]["code"], -32602);
+
+    // Unknown key codes don't take the server down.
+    let response = server.call("key", json!({"key": 9999}));
+    assert_eq!(response["error"]["code"], -32603);
+
+    // Notifications have no response.
+    writeln!(
+        server.stdin,
+        r#"{{"jsonrpc": "2.0", "method": "key", "params": {{"key": {}}}}}"#,
+        riti::keycodes::VC_A
+    )
+    .unwrap();
+    let response = server.call("key", json!({"key": riti::keycodes::VC_M}));
+    assert_eq!(response["result"]["auxiliary"], "am");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_socket() {
+    use std::os::unix::net::UnixStream;
+    use std::time::Duration;
+
+    let dir = std::env::temp_dir().join(format!("riti-server-socket-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let socket = dir.join("riti.sock");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_riti-server"))
+        .arg("--socket")
+        .arg(&socket)
+        .spawn()
+        .unwrap();
+
+    // Wait for the server to listen.
+    let mut stream = (0..100)
+        .find_map(|_| {
+            UnixStream::connect(&socket).ok().or_else(|| {
+                std::thread::sleep(Duration::from_millis(50));
+                None
+            })
+        })
+        .unwrap();
+
+    writeln!(
+        stream,
+        r#"{{"jsonrpc": "2.0", "id": 7, "method": "transliterate", "params": {{"text": "ami"}}}}"#
+    )
+    .unwrap();
+    let mut response = String::new();
+    BufReader::new(&stream).read_line(&mut response).unwrap();
+    let response: Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(
+        response,
+        json!({"jsonrpc": "2.0", "id": 7, "result": "আমি"})
+    );
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}